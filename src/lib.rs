@@ -0,0 +1,164 @@
+//! A simple Computer Algebra System. Expressions are read into a binary tree of [`Node`]s, obeying the rules of recursive PEMDAS, which
+//! can then be simplified, evaluated and printed. Numbers are exact [`Rational`]s of arbitrary-precision [`Integer`]s, so `1 / 3 * 3`
+//! simplifies to exactly `1` and `2 ^ 100` never overflows, even when an expression is evaluated. An expression can be read with any
+//! other [`Numeric`] type instead, eg. to be evaluated in floating point, see [`parse_as`].
+//!
+//! ```
+//! let node = rust_cas::parse("x + 2 + x").unwrap();
+//! let simplified = rust_cas::simplify(&node);
+//! assert_eq!(simplified.to_string(), "2 + 2 * x");
+//!
+//! let mut values = std::collections::HashMap::new();
+//! values.insert("x".to_string(), rust_cas::Rational::from(3));
+//! assert_eq!(rust_cas::evaluate(&simplified, &values), Some(rust_cas::Rational::from(8)));
+//! ```
+//!
+//! Input that can't be read is a [`ParseError`] rather than a crash:
+//!
+//! ```
+//! use rust_cas::ParseError;
+//!
+//! assert_eq!(rust_cas::parse("x +"), Err(ParseError::ExpectedValue));
+//! assert_eq!(rust_cas::parse(")"), Err(ParseError::UnbalancedGroups));
+//! ```
+//!
+//! Expressions can also be built in Rust with [`sym`], [`num`] and the usual operators:
+//!
+//! ```
+//! use rust_cas::{num, sym, Rational};
+//! use std::convert::TryFrom;
+//!
+//! let x = sym("x");
+//! let expr = x.pow(2) + 3 * &x - &x / 2;
+//! assert_eq!(expr.simplify(), x.pow(2) + num(Rational::new(5, 2)) * &x);
+//! assert_eq!(Rational::try_from(2.5), Ok(Rational::new(5, 2)));
+//! assert!(Rational::try_from(f64::NAN).is_err());
+//! ```
+
+// The codebase favours explicit returns and field names, and keeps the token names upper case.
+#![allow(clippy::needless_return, clippy::upper_case_acronyms, clippy::redundant_field_names)]
+
+use std::collections::HashMap;
+
+pub(crate) mod tree;
+
+pub use tree::{Inequality, Node};
+pub use tree::assume::{Assumption, Assumptions};
+pub use tree::bigfloat::BigFloat;
+pub use tree::build::{num, sym};
+pub use tree::codegen::Language;
+pub use tree::complex::{Complex, Real};
+pub use tree::error::ParseError;
+pub use tree::expr::Expr;
+pub use tree::integer::Integer;
+pub use tree::numeric::Numeric;
+pub use tree::rational::Rational;
+pub use tree::store::{ExprId, ExprStore, Stored};
+pub use tree::symbol::Symbol;
+pub use tree::token::Token;
+pub use tree::visit::{fold_operands, walk, walk_mut, Fold, MutVisitor, Visitor};
+
+/// Split an expression into its tokens. Values and operators are separated by spaces, eg. `2 * ( x + 1 )`.
+pub fn tokenize(input: &str) -> Vec<Token> {
+    let strings: Vec<String> = input.split_ascii_whitespace().map(|a| a.to_string()).collect();
+    return tree::token::tokenize(strings)
+}
+
+/// Read an expression into its binary tree, without simplifying it.
+pub fn parse(input: &str) -> Result<Box<Node>, ParseError> {
+    return parse_as(input)
+}
+
+/// Read an expression into its binary tree with its numbers as `N`, eg. `f64` to simplify in floating point, or [`BigFloat`] for a
+/// floating point with 256 bits of precision. Every expression simplified from it keeps the same type of number.
+///
+/// ```
+/// let node = rust_cas::parse_as::<f64>("x * 0.1 + x * 0.2").unwrap();
+/// assert_eq!(rust_cas::simplify(&node).to_string(), "0.30000000000000004 * x");
+///
+/// let node = rust_cas::parse_as::<rust_cas::BigFloat>("2 ^ 0.5").unwrap();
+/// assert!(rust_cas::simplify(&node).to_string().starts_with("1.41421356237309504880168872420969807856967187537694"));
+/// ```
+///
+/// With [`Complex`] numbers, `i` is the imaginary unit, so square roots of negative numbers have a value:
+///
+/// ```
+/// use rust_cas::{Complex, Rational};
+///
+/// let node = rust_cas::parse_as::<Complex<Rational>>("( 0 - 2 + ( 2 ^ 2 - 4 * 1 * 5 ) ^ 0.5 ) / 2").unwrap();
+/// assert_eq!(rust_cas::simplify(&node).to_string(), "-1 + 2 * i");
+///
+/// let node = rust_cas::parse_as::<Complex<Rational>>("i ^ 2 + 1 / ( 1 + i )").unwrap();
+/// assert_eq!(rust_cas::simplify(&node).to_string(), "-1 / 2 - 1 / 2 * i");
+/// ```
+pub fn parse_as<N: Numeric>(input: &str) -> Result<Box<Node<N>>, ParseError> {
+    let strings: Vec<String> = input.split_ascii_whitespace().map(|a| a.to_string()).collect();
+    return tree::build(tree::token::tokenize(strings))
+}
+
+/// Read an S-expression such as `(+ (* 2 x) (^ y 3))` into its binary tree, the reverse of `Node::to_sexpr`.
+///
+/// ```
+/// let node = rust_cas::parse("x + 2 * y ^ 3").unwrap();
+/// assert_eq!(node.to_sexpr(), "(+ x (* 2 (^ y 3)))");
+/// assert_eq!(rust_cas::parse_sexpr(&node.to_sexpr()), Ok(node));
+/// ```
+pub fn parse_sexpr(input: &str) -> Result<Box<Node>, ParseError> {
+    return tree::sexpr::parse(input)
+}
+
+/// Read a chain of comparisons such as `0 < x <= 10`, without simplifying its terms.
+pub fn parse_inequality(input: &str) -> Result<Inequality, ParseError> {
+    return parse_inequality_as(input)
+}
+
+/// Read a chain of comparisons with its numbers as `N`, see `parse_as`.
+pub fn parse_inequality_as<N: Numeric>(input: &str) -> Result<Inequality<N>, ParseError> {
+    let strings: Vec<String> = input.split_ascii_whitespace().map(|a| a.to_string()).collect();
+    return tree::build_inequality(tree::token::tokenize(strings))
+}
+
+/// Simplify an expression, folding `NUM` values and collecting like terms. A root is only folded when it is exact, and otherwise kept as a
+/// power with any exact factor taken out of it.
+///
+/// ```
+/// let node = rust_cas::parse("8 ^ ( 1 / 3 ) + 12 ^ 0.5 + 2 ^ 0.5 * 3 ^ 0.5").unwrap();
+/// assert_eq!(rust_cas::simplify(&node).to_string(), "2 + 6 ^ (1 / 2) + 2 * 3 ^ (1 / 2)");
+/// ```
+pub fn simplify<N: Numeric>(node: &Node<N>) -> Box<Node<N>> {
+    return tree::simplify(node)
+}
+
+/// Simplify an expression, also applying the rewrites that only hold under `assumptions`, eg. `x / x` is `1` once `x` is assumed to be
+/// nonzero.
+///
+/// ```
+/// use rust_cas::{Assumption, Assumptions};
+///
+/// let node = rust_cas::parse("x / x").unwrap();
+/// assert_eq!(*rust_cas::simplify(&node), *node);
+///
+/// let mut assumptions = Assumptions::new();
+/// assumptions.assume("x", Assumption::Nonzero);
+/// assert_eq!(*rust_cas::simplify_with(&node, &assumptions), rust_cas::Node::num(1));
+/// ```
+pub fn simplify_with<N: Numeric>(node: &Node<N>, assumptions: &Assumptions) -> Box<Node<N>> {
+    return tree::simplify_with(node, assumptions)
+}
+
+/// Numerically evaluate an expression in its own numbers, with `values` holding the value of each `VAR`. Returns `None` if a `VAR` has no
+/// value, or the expression has no value in `N`, see `Node::evaluate`.
+pub fn evaluate<N: Numeric>(node: &Node<N>, values: &HashMap<String, N>) -> Option<N> {
+    return node.evaluate(values)
+}
+
+/// The tree of an expression before and after simplification side by side in one Graphviz DOT graph, see `Node::to_dot`.
+pub fn dot_comparison<N: Numeric>(before: &Node<N>, after: &Node<N>) -> String {
+    return tree::dot::comparison(before, after)
+}
+
+/// The terms of a chain of inequalities before and after simplification side by side in one Graphviz DOT graph, with a dashed edge for
+/// each comparison between them.
+pub fn dot_inequality_comparison<N: Numeric>(before: &Inequality<N>, after: &Inequality<N>) -> String {
+    return tree::dot::inequality_comparison(before, after)
+}
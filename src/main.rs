@@ -1,35 +1,95 @@
-mod tree;
-use tree::token as token;
+use rust_cas::{BigFloat, Complex, Numeric, ParseError, Token};
 
 fn main() {
+    // With --dot, print the tree of an expression (or of each term of a chain of inequalities) before and after simplification as a
+    // Graphviz graph instead of as text. The prompt goes to stderr then, so the graph can be piped straight into dot.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let dot: bool = args.iter().any(|a| a == "--dot");
+
+    // With --pretty, print the expressions over several lines, with stacked fractions and raised exponents drawn in Unicode, or with
+    // --ascii, drawn in ASCII only.
+    let pretty: Option<bool> = if args.iter().any(|a| a == "--pretty") {
+        Some(true)
+    } else if args.iter().any(|a| a == "--ascii") {
+        Some(false)
+    } else {
+        None
+    };
+
     // Get user input
     let mut input = String::new();
-    println!("Expression to simplify: ");
+    if dot {
+        eprintln!("Expression to simplify: ");
+    } else {
+        println!("Expression to simplify: ");
+    }
     std::io::stdin().read_line(&mut input).unwrap();
 
-    // Create a vector of str objects based on the user input, splitting the expression via spaces and then collecting them. 
-    let v: Vec<&str> = input.split_ascii_whitespace().collect();
+    // The numbers are exact rationals, unless --f32, --f64 or --bigfloat asks to simplify in floating point. With --complex, they are
+    // complex numbers over those, and i is the imaginary unit.
+    let result: Result<(), ParseError> = if args.iter().any(|a| a == "--complex") {
+        if args.iter().any(|a| a == "--f32") {
+            run::<Complex<f32>>(&input, dot, pretty)
+        } else if args.iter().any(|a| a == "--f64") {
+            run::<Complex<f64>>(&input, dot, pretty)
+        } else if args.iter().any(|a| a == "--bigfloat") {
+            run::<Complex<BigFloat>>(&input, dot, pretty)
+        } else {
+            run::<Complex<rust_cas::Rational>>(&input, dot, pretty)
+        }
+    } else if args.iter().any(|a| a == "--f32") {
+        run::<f32>(&input, dot, pretty)
+    } else if args.iter().any(|a| a == "--f64") {
+        run::<f64>(&input, dot, pretty)
+    } else if args.iter().any(|a| a == "--bigfloat") {
+        run::<BigFloat>(&input, dot, pretty)
+    } else {
+        run::<rust_cas::Rational>(&input, dot, pretty)
+    };
 
-    // Convert these to Strings
-    let mut solid: Vec<String> = Vec::new();
-    for i in v {
-        solid.push(i.to_string())
+    // Input that can't be read is reported instead of simplified
+    if let Err(a) = result {
+        eprintln!("{}", a);
+        std::process::exit(1);
     }
+}
 
-    // Get the length of the String vector
-    let solid_length: usize = solid.len();
-
-    // If the vector ends with a new line, remove it.
-    if solid[solid_length - 1].contains("\n") {
-        solid[solid_length - 1] = solid[solid_length - 1].replace("\n", "");
+fn run<N: Numeric>(input: &str, dot: bool, pretty: Option<bool>) -> Result<(), ParseError> {
+    // If the input holds comparisons, it is a chain of inequalities instead of a single expression.
+    if rust_cas::tokenize(input).iter().any(Token::is_comparison) {
+        let a: rust_cas::Inequality<N> = rust_cas::parse_inequality_as(input)?;
+        if dot {
+            print!("{}", rust_cas::dot_inequality_comparison(&a, &a.simplify()));
+            return Ok(());
+        }
+        match pretty {
+            Some(unicode) => {
+                println!("Before Simplifcation:\n{}\n", a.to_pretty(unicode));
+                println!("After simplification:\n{}", a.simplify().to_pretty(unicode));
+            }
+            None => {
+                println!("Before Simplifcation: {}", a);
+                println!("After simplification: {}", a.simplify());
+            }
+        }
+        return Ok(());
     }
-    
-    // Get our token vector.
-    let a: Vec<token::Token> = token::tokenize(solid);
-    println!("Token Vector: {:?}", a);
-    
-    // Proccess the vector.
-    let b: Box<tree::Node> = tree::process(a);
-    println!("After simplification: {:#?}", b);
 
+    // Proccess the expression.
+    let a: Box<rust_cas::Node<N>> = rust_cas::parse_as(input)?;
+    if dot {
+        print!("{}", rust_cas::dot_comparison(&a, &rust_cas::simplify(&a)));
+        return Ok(());
+    }
+    match pretty {
+        Some(unicode) => {
+            println!("Before Simplifcation:\n{}\n", a.to_pretty(unicode));
+            println!("After simplification:\n{}", rust_cas::simplify(&a).to_pretty(unicode));
+        }
+        None => {
+            println!("Before Simplifcation: {}", a);
+            println!("After simplification: {}", rust_cas::simplify(&a));
+        }
+    }
+    Ok(())
 }
@@ -3,35 +3,277 @@
 // just remove it outright in favour of multiplication of negative 1.
 
 pub mod token;
+pub mod assume;
+pub mod bigfloat;
+pub mod build;
+pub mod codegen;
+pub mod complex;
+pub mod dot;
+pub mod error;
+pub mod expr;
+pub mod integer;
+mod latex;
+mod mathml;
+pub mod numeric;
+pub mod order;
+mod pretty;
+mod print;
+pub mod rational;
+pub mod sexpr;
+pub mod store;
+pub mod symbol;
+pub mod visit;
+
+use error::ParseError;
+use numeric::Numeric;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Node scructure, representing the Data type, and a recursive definition of Option Nodes. The data_type can be any Token, and if it is a NUM or VAR, 
+/// the left and right nodes will always be None. Because this is a recursive structure, we will have to add it onto the heap instead of the stack.
+/// We do this with the Box function.
+///
+/// With the `serde` feature a Node is serialized as `{"data_type": ..., "left": ..., "right": ...}`, leaving out the left and right of NUM
+/// and VAR values. See the README for the full JSON schema. Reading one checks it the same way as `Node::operation`, so an operator
+/// without both of its sides, a NUM or VAR with a side, or a token that can't be part of an expression (eg. `LGROUP`) is an error.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "N: Numeric + serde::Deserialize<'de>"), try_from = "UncheckedNode<N>"))]
+pub struct Node<N = rational::Rational> {
+    data_type: token::Token<N>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    left: Option<Box<Node<N>>>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    right: Option<Box<Node<N>>>,
+}
+
+// A Node as it is read, before it is checked to have the sides its token needs.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound(deserialize = "N: Numeric + serde::Deserialize<'de>"))]
+struct UncheckedNode<N> {
+    data_type: token::Token<N>,
+    #[serde(default)]
+    left: Option<Box<Node<N>>>,
+    #[serde(default)]
+    right: Option<Box<Node<N>>>,
+}
+
+// A long sum or product is a chain as deep as it is long (a + b + c is (a + b) + c), so a Node is cloned and dropped with a stack of the
+// nodes still to go, rather than recursively with a call for every level of the chain.
+impl<N: Clone> Clone for Node<N> {
+    fn clone(&self) -> Node<N> {
+        // Each node is seen once before its sides, and once after them to join their clones, which are on top of cloned by then.
+        let mut pending: Vec<(&Node<N>, bool)> = vec![(self, false)];
+        let mut cloned: Vec<Node<N>> = Vec::new();
+        while let Some((node, joined)) = pending.pop() {
+            if !joined {
+                pending.push((node, true));
+                pending.extend(node.right.as_deref().map(|a| (a, false)));
+                pending.extend(node.left.as_deref().map(|a| (a, false)));
+                continue;
+            }
+            let right: Option<Box<Node<N>>> = node.right.as_ref().map(|_| Box::new(cloned.pop().unwrap()));
+            let left: Option<Box<Node<N>>> = node.left.as_ref().map(|_| Box::new(cloned.pop().unwrap()));
+            cloned.push(Node {
+                data_type: node.data_type.clone(),
+                left: left,
+                right: right,
+            });
+        }
+        return cloned.pop().unwrap()
+    }
+}
+
+impl<N> Drop for Node<N> {
+    fn drop(&mut self) {
+        // Every node is dropped once its sides are taken out of it, so none of them drops a side of its own.
+        let mut pending: Vec<Box<Node<N>>> = Vec::new();
+        pending.extend(self.left.take());
+        pending.extend(self.right.take());
+        while let Some(mut node) = pending.pop() {
+            pending.extend(node.left.take());
+            pending.extend(node.right.take());
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<N: Numeric> std::convert::TryFrom<UncheckedNode<N>> for Node<N> {
+    type Error = String;
+
+    fn try_from(node: UncheckedNode<N>) -> Result<Node<N>, String> {
+        let sides: bool = match node.data_type {
+            token::Token::ADD | token::Token::MUL | token::Token::DIV | token::Token::EXP => true,
+            token::Token::NUM(_) | token::Token::VAR(_) => false,
+            a => return Err(format!("{:?} isn't an operation!", a)),
+        };
+        if sides && (node.left.is_none() || node.right.is_none()) {
+            return Err(format!("The operator {:?} is missing a value!", node.data_type))
+        } else if !sides && (node.left.is_some() || node.right.is_some()) {
+            return Err(format!("The value {:?} can't have a left or right side!", node.data_type))
+        }
+        return Ok(Node {
+            data_type: node.data_type,
+            left: node.left,
+            right: node.right,
+        })
+    }
+}
+
+impl<N: Numeric> Node<N> {
+    /// A NUM value.
+    pub fn num<M: Into<N>>(num: M) -> Node<N> {
+        return Node {
+            data_type: token::Token::NUM(num.into()),
+            left: None,
+            right: None,
+        }
+    }
+
+    /// A VAR value.
+    pub fn var(var: &str) -> Node<N> {
+        return Node {
+            data_type: token::Token::VAR(symbol::Symbol::new(var)),
+            left: None,
+            right: None,
+        }
+    }
+
+    /// An operation between two nodes. `data_type` must be one of `ADD`, `MUL`, `DIV` or `EXP`.
+    pub fn operation(data_type: token::Token<N>, left: Node<N>, right: Node<N>) -> Node<N> {
+        match data_type {
+            token::Token::ADD | token::Token::MUL | token::Token::DIV | token::Token::EXP => {}
+            a => panic!("{:?} isn't an operation!", a),
+        }
+        return Node {
+            data_type: data_type,
+            left: Some(Box::new(left)),
+            right: Some(Box::new(right)),
+        }
+    }
+
+    /// The token of this node, a `NUM` or `VAR` value, or the operation between the left and right nodes.
+    pub fn data_type(&self) -> &token::Token<N> {
+        return &self.data_type
+    }
+
+    /// The left side of an operation, `None` for `NUM` and `VAR` values.
+    pub fn left(&self) -> Option<&Node<N>> {
+        return self.left.as_deref()
+    }
+
+    /// The right side of an operation, `None` for `NUM` and `VAR` values.
+    pub fn right(&self) -> Option<&Node<N>> {
+        return self.right.as_deref()
+    }
+
+    // The token and sides of the node, taken out of it, since a Node can't be destructured with a Drop.
+    fn into_parts(mut self) -> (token::Token<N>, Branch<N>, Branch<N>) {
+        let left: Branch<N> = self.left.take();
+        let right: Branch<N> = self.right.take();
+        let data_type: token::Token<N> = std::mem::replace(&mut self.data_type, token::Token::LGROUP);
+        return (data_type, left, right)
+    }
+
+    /// Numerically evaluate the node in its own numbers, with `values` holding the value of each VAR. Returns `None` if a VAR has no value,
+    /// a value is divided by zero, or a power can't be stored in `N` (see `Numeric::pow`), eg. 2^(1/2) as a `Rational`. An expression
+    /// read with `parse_as::<f64>` evaluates in floating point.
+    pub fn evaluate(&self, values: &HashMap<String, N>) -> Option<N> {
+        // Each node is seen once before its sides, and once after them to fold their values, like in clone.
+        let mut pending: Vec<(&Node<N>, bool)> = vec![(self, false)];
+        let mut evaluated: Vec<N> = Vec::new();
+        while let Some((node, folded)) = pending.pop() {
+            match &node.data_type {
+                token::Token::NUM(a) => {
+                    evaluated.push(a.clone());
+                    continue;
+                }
+                token::Token::VAR(a) => {
+                    evaluated.push(values.get(a.as_str())?.clone());
+                    continue;
+                }
+                _ if !folded => {
+                    pending.push((node, true));
+                    pending.push((node.right.as_deref()?, false));
+                    pending.push((node.left.as_deref()?, false));
+                    continue;
+                }
+                _ => {}
+            }
+            let right: N = evaluated.pop()?;
+            let left: N = evaluated.pop()?;
+            match node.data_type {
+                token::Token::ADD => evaluated.push(left + right),
+                token::Token::MUL => evaluated.push(left * right),
+                token::Token::DIV if right.is_zero() => return None,
+                token::Token::DIV => evaluated.push(left / right),
+                token::Token::EXP => evaluated.push(left.pow(&right)?),
+                _ => return None,
+            }
+        }
+        return evaluated.pop()
+    }
+}
 
-// Node scructure, representing the Data type, and a recursive definition of Option Nodes. The data_type can be any Token, and if it is a NUM or VAR, 
-// the left and right nodes will always be None. Because this is a recursive structure, we will have to add it onto the heap instead of the stack.
-// We do this with the Box function.
+/// A chain of comparisons such as 0 < x <= 10, stating an interval constraint. Each comparison holds between the terms on either side of it,
+/// so the chain is the conjunction of those relations. All of the comparisons in a chain point the same way.
 #[derive(Debug)]
 #[derive(Clone)]
-pub struct Node {
-    data_type: token::Token,
-    left: Option<Box<Node>>,
-    right: Option<Box<Node>>,
+pub struct Inequality<N = rational::Rational> {
+    // The terms of the chain, from left to right. comparisons[i] sits between terms[i] and terms[i + 1].
+    terms: Vec<Node<N>>,
+    comparisons: Vec<token::Token<N>>,
+}
+
+impl<N: Numeric> Inequality<N> {
+    /// The terms of the chain, from left to right.
+    pub fn terms(&self) -> &[Node<N>] {
+        return &self.terms
+    }
+
+    /// The comparisons of the chain, `comparisons()[i]` sits between `terms()[i]` and `terms()[i + 1]`.
+    pub fn comparisons(&self) -> &[token::Token<N>] {
+        return &self.comparisons
+    }
+
+    /// Simplify every term of the chain.
+    pub fn simplify(&self) -> Inequality<N> {
+        return Inequality {
+            terms: self.terms.iter().map(|a| *simplify(a)).collect(),
+            comparisons: self.comparisons.clone(),
+        }
+    }
+
+    /// Split the chain into the relations it is a conjunction of, eg. `0 < x <= 10` is `0 < x` and `x <= 10`.
+    pub fn relations(&self) -> Vec<(Node<N>, token::Token<N>, Node<N>)> {
+        let mut relations: Vec<(Node<N>, token::Token<N>, Node<N>)> = Vec::new();
+        for (i, comparison) in self.comparisons.iter().enumerate() {
+            relations.push((self.terms[i].clone(), comparison.clone(), self.terms[i + 1].clone()));
+        }
+        return relations
+    }
 }
 
-fn type_declare(left: Option<Box<Node>>, right: Option<Box<Node>>) -> (Option<f32>, Option<f32>, Option<String>, Option<String>, Option<i32>, Option<i32>) {
-    let left_num: Option<f32>;
-    let right_num: Option<f32>;
-    let left_var: Option<String>;
-    let right_var: Option<String>;
+// The legacy simplifier decomposes a node into a tuple of its parts, and matches on them with chains of comparisons to None.
+#[allow(clippy::type_complexity)]
+fn type_declare<N: Numeric>(left: &Option<Box<Node<N>>>, right: &Option<Box<Node<N>>>) -> (Option<N>, Option<N>, Option<symbol::Symbol>, Option<symbol::Symbol>, Option<i32>, Option<i32>) {
+    let left_num: Option<N>;
+    let right_num: Option<N>;
+    let left_var: Option<symbol::Symbol>;
+    let right_var: Option<symbol::Symbol>;
     let left_type: Option<i32>;
     let right_type: Option<i32>;
     match left {
         Some(c) => {
-            match c.data_type {
+            match &c.data_type {
                 token::Token::NUM(b) => {
-                    left_num = Some(b);
+                    left_num = Some(b.clone());
                     left_var = None;
                     left_type = None;
                 }
                 token::Token::VAR(b) => {
-                    left_var = Some(b);
+                    left_var = Some(*b);
                     left_num = None;
                     left_type = None;
                 }
@@ -70,14 +312,14 @@ fn type_declare(left: Option<Box<Node>>, right: Option<Box<Node>>) -> (Option<f3
     }
     match right {
         Some(c) => {
-            match c.data_type {
+            match &c.data_type {
                 token::Token::NUM(b) => {
-                    right_num = Some(b);
+                    right_num = Some(b.clone());
                     right_var = None;
                     right_type = None;
                 }
                 token::Token::VAR(b) => {
-                    right_var = Some(b);
+                    right_var = Some(*b);
                     right_num = None;
                     right_type = None;
                 }
@@ -117,7 +359,7 @@ fn type_declare(left: Option<Box<Node>>, right: Option<Box<Node>>) -> (Option<f3
     return (left_num, right_num, left_var, right_var, left_type, right_type)
 }
 
-fn create_node_from_var(var: String) -> Option<Box<Node>> {
+fn create_node_from_var<N: Numeric>(var: symbol::Symbol) -> Option<Box<Node<N>>> {
     return Some(Box::new(Node {
         data_type: token::Token::VAR(var),
         left: None,
@@ -125,7 +367,7 @@ fn create_node_from_var(var: String) -> Option<Box<Node>> {
     }))
 }
 
-fn create_node_from_num(num: f32) -> Option<Box<Node>> {
+fn create_node_from_num<N: Numeric>(num: N) -> Option<Box<Node<N>>> {
     return Some(Box::new(Node {
         data_type: token::Token::NUM(num),
         left: None,
@@ -133,7 +375,7 @@ fn create_node_from_num(num: f32) -> Option<Box<Node>> {
     }))
 }
 
-fn stock_node(data_type: token::Token, left: Option<Box<Node>>, right: Option<Box<Node>>) -> Option<Box<Node>> {
+fn stock_node<N: Numeric>(data_type: token::Token<N>, left: Option<Box<Node<N>>>, right: Option<Box<Node<N>>>) -> Option<Box<Node<N>>> {
     return Some(Box::new(Node {
         data_type: data_type,
         left: left,
@@ -141,1140 +383,1057 @@ fn stock_node(data_type: token::Token, left: Option<Box<Node>>, right: Option<Bo
     }))
 }
 
-fn simplify_node(node: Option<Box<Node>>) -> Option<Box<Node>> {
-    match node.clone() {
-        Some(a) => {
-           match a.data_type {
-               token::Token::NUM(_) => {
-                    return Some(a)
-               }
-               token::Token::VAR(_) => {
-                    return Some(a)
-               }
-               _ => {
-                    // Find left and right
-                    let mut left: Option<Box<Node>> = simplify_node(a.left);
-                    let mut right: Option<Box<Node>> = simplify_node(a.right);
-
-                    // Switch left vs. right if left is var and right is num if and operator is addition or multiplication
-                    match a.data_type {
-                        token::Token::ADD | token::Token::MUL => {
-                            match left.clone().unwrap().data_type {
-                                // Switch variables to the right if they are left
-                                token::Token::VAR(_) => {
-                                    match right.clone().unwrap().data_type {
-                                        token::Token::NUM(_) => {
-                                            let temp_left: Option<Box<Node>> = right;
-                                            right = left;
-                                            left = temp_left;
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                                // Switch Operaters to right if they are left
-                                token::Token::DIV | token::Token::ADD | token::Token::MUL | token::Token::EXP => {
-                                    match right.clone().unwrap().data_type {
-                                        token::Token::NUM(_) | token::Token::VAR(_) => {
-                                            let temp_left: Option<Box<Node>> = right;
-                                            right = left;
-                                            left = temp_left;
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-                        _ => {}
-                    }
-                    
-                    // decompose it (i am too lazy for matching)
-                    let decomposed: (Option<f32>, Option<f32>, Option<String>, Option<String>, Option<i32>, Option<i32>) = type_declare(left.clone(), right.clone());
-                    let left_num: Option<f32> = decomposed.0;
-                    let right_num: Option<f32> = decomposed.1;
-                    let left_var: Option<String> = decomposed.2;
-                    let right_var: Option<String> = decomposed.3;
-                    let left_type: Option<i32> = decomposed.4;
-                    let right_type: Option<i32> = decomposed.5;
-
-                    // decompose left_left and left_right
-                    let left_left_num: Option<f32>;
-                    let left_right_num: Option<f32>;
-                    let left_left_var: Option<String>;
-                    let left_right_var: Option<String>;
-
-                    // decompose right_left and right_right
-                    let right_left_num: Option<f32>;
-                    let right_right_num: Option<f32>;
-                    let right_left_var: Option<String>;
-                    let right_right_var: Option<String>;
-                    let right_right_type: Option<i32>;
-
-                    // decompose right_right_left and right_right_right
-                    let right_right_left_num: Option<f32>;
-                    let right_right_right_num: Option<f32>;
-                    let right_right_left_var: Option<String>;
-                    let right_right_right_var: Option<String>;
-
-                    // Check to see if we must decompose left_left and left_right
-                    if left_type != None {
-                        let left_decomposed: (Option<f32>, Option<f32>, Option<String>, Option<String>, Option<i32>, Option<i32>) = type_declare(left.clone().unwrap().left, left.clone().unwrap().right);
-                        left_left_num = left_decomposed.0;
-                        left_right_num = left_decomposed.1;
-                        left_left_var = left_decomposed.2;
-                        left_right_var = left_decomposed.3;
-
-                    // if not, set all to None
-                    } else {
-                        left_left_num = None;
-                        left_right_num = None;
-                        left_left_var = None;
-                        left_right_var = None;
-                    }
+// Every VAR in a branch, in the order they appear.
+fn node_vars<N: Numeric>(node: &Option<Box<Node<N>>>, vars: &mut Vec<symbol::Symbol>) {
+    let mut pending: Vec<&Node<N>> = node.as_deref().into_iter().collect();
+    while let Some(a) = pending.pop() {
+        if let token::Token::VAR(b) = a.data_type {
+            vars.push(b);
+        }
+        pending.extend(a.right.as_deref());
+        pending.extend(a.left.as_deref());
+    }
+}
 
-                    // check to see if we must decompose right_left and right_right
-                    if right_type != None {
-                        let right_decomposed: (Option<f32>, Option<f32>, Option<String>, Option<String>, Option<i32>, Option<i32>) = type_declare(right.clone().unwrap().left, right.clone().unwrap().right);
-                        right_left_num = right_decomposed.0;
-                        right_right_num = right_decomposed.1;
-                        right_left_var = right_decomposed.2;
-                        right_right_var = right_decomposed.3;
-                        right_right_type = right_decomposed.5;
-
-                        // check to see if we must decompose right_right_left and right_right_right
-                        if right_right_type != None {
-                            let right_right_decompose: (Option<f32>, Option<f32>, Option<String>, Option<String>, Option<i32>, Option<i32>) = type_declare(right.clone().unwrap().right.clone().unwrap().left, right.clone().unwrap().right.clone().unwrap().right);
-                            right_right_left_num = right_right_decompose.0;
-                            right_right_right_num = right_right_decompose.1;
-                            right_right_left_var = right_right_decompose.2;
-                            right_right_right_var = right_right_decompose.3;
-
-                        // if not, set all to none
-                        } else {
-                            right_right_left_num = None;
-                            right_right_right_num = None;
-                            right_right_left_var = None;
-                            right_right_right_var = None;
-                        }
+// A side of a node, None for the sides of a NUM or VAR.
+type Branch<N> = Option<Box<Node<N>>>;
+
+// Whether simplifying the node could divide a VAR by itself, eg. x / x or x * (2 / x^3), without that VAR being assumed to be nonzero.
+fn divides_var<N: Numeric>(data_type: &token::Token<N>, left: &Option<Box<Node<N>>>, right: &Option<Box<Node<N>>>, assumptions: &assume::Assumptions) -> bool {
+    let is_div = |side: &Option<Box<Node<N>>>| -> bool {
+        return matches!(side.as_ref().map(|a| &a.data_type), Some(token::Token::DIV))
+    };
+    // Find what is being divided, and what it is divided by
+    let (numerator, divisor): (&Branch<N>, &Branch<N>) = match data_type {
+        token::Token::DIV => (left, right),
+        token::Token::MUL if is_div(right) => (left, &right.as_ref().unwrap().right),
+        token::Token::MUL if is_div(left) => (right, &left.as_ref().unwrap().right),
+        _ => return false,
+    };
+    let mut numerator_vars: Vec<symbol::Symbol> = Vec::new();
+    let mut divisor_vars: Vec<symbol::Symbol> = Vec::new();
+    node_vars(numerator, &mut numerator_vars);
+    node_vars(divisor, &mut divisor_vars);
+    return divisor_vars.iter().any(|a| numerator_vars.contains(a) && !assumptions.is_nonzero(&expr::Expr::<N>::Sym(*a)))
+}
 
-                    // if not, set all to None
-                    } else {
-                        right_left_num = None;
-                        right_right_num = None;
-                        right_left_var = None;
-                        right_right_var = None;
-                        right_right_type = None;
-                        right_right_left_num = None;
-                        right_right_right_num = None;
-                        right_right_left_var = None;
-                        right_right_right_var = None;
-                    }
+// Whether the node is a DIV, or a MUL of a DIV, with a 0 in the two levels below it that the rules in simplify_node match on, eg. x / 0
+// or 2 * (x / 0).
+fn divides_zero<N: Numeric>(data_type: &token::Token<N>, left: &Option<Box<Node<N>>>, right: &Option<Box<Node<N>>>) -> bool {
+    let is_div = |side: &Option<Box<Node<N>>>| -> bool {
+        return matches!(side.as_ref().map(|a| &a.data_type), Some(token::Token::DIV))
+    };
+    let is_zero = |side: &Option<Box<Node<N>>>| -> bool {
+        return matches!(side.as_ref().map(|a| &a.data_type), Some(token::Token::NUM(b)) if b.is_zero())
+    };
+    match data_type {
+        token::Token::DIV => {}
+        token::Token::MUL if is_div(left) || is_div(right) => {}
+        _ => return false,
+    }
+    return [left, right].iter().any(|side| {
+        return is_zero(side) || side.as_ref().is_some_and(|a| is_zero(&a.left) || is_zero(&a.right))
+    })
+}
 
-                    // *
-                    //          (OP)
-                    //  (NUM)           (NUM)
-                    if left_num != None && right_num != None {
-                        let d: Option<Box<Node>>;
-                        match a.data_type {
+// A step of simplifying a tree: simplify a branch, or join the last two branches simplified under an operator.
+enum Step<N> {
+    Branch(Branch<N>),
+    Join(token::Token<N>),
+}
 
-                            // Add the two NUMs
-                            token::Token::ADD => {
-                                d = create_node_from_num(left_num.unwrap() + right_num.unwrap());
-                            }
+// Simplify every branch from the bottom up with the patterns in simplify_branches. The branches are simplified with a stack of steps
+// rather than recursively, since a long sum or product is a chain as deep as it is long (a + b + c is (a + b) + c.)
+fn simplify_node<N: Numeric>(node: Branch<N>, assumptions: &assume::Assumptions) -> Branch<N> {
+    let mut steps: Vec<Step<N>> = vec![Step::Branch(node)];
+    let mut simplified: Vec<Branch<N>> = Vec::new();
+    while let Some(step) = steps.pop() {
+        match step {
+            Step::Branch(Some(a)) if !matches!(a.data_type, token::Token::NUM(_) | token::Token::VAR(_)) => {
+                let (data_type, left, right): (token::Token<N>, Branch<N>, Branch<N>) = a.into_parts();
+                steps.push(Step::Join(data_type));
+                steps.push(Step::Branch(right));
+                steps.push(Step::Branch(left));
+            }
+            Step::Branch(a) => simplified.push(a),
+            Step::Join(data_type) => {
+                let right: Branch<N> = simplified.pop().unwrap();
+                let left: Branch<N> = simplified.pop().unwrap();
+                simplified.push(simplify_branches(data_type, left, right, assumptions));
+            }
+        }
+    }
+    return simplified.pop().unwrap()
+}
 
-                            // Multiply the two NUMs
-                            token::Token::MUL => {
-                                d = create_node_from_num(left_num.unwrap() * right_num.unwrap());
-                            }
+// Simplify an operation whose branches are already simplified.
+#[allow(clippy::partialeq_to_none, clippy::type_complexity, clippy::collapsible_match)]
+fn simplify_branches<N: Numeric>(data_type: token::Token<N>, mut left: Branch<N>, mut right: Branch<N>, assumptions: &assume::Assumptions) -> Option<Box<Node<N>>> {
+    // Put the branches of ADD and MUL in the canonical order, so a NUM is always left of a VAR, and a VAR is always left of an operator
+    match data_type {
+        token::Token::ADD | token::Token::MUL => {
+            if order::cmp_node(left.as_ref().unwrap(), right.as_ref().unwrap()) == Ordering::Greater {
+                std::mem::swap(&mut left, &mut right);
+            }
+        }
+        _ => {}
+    }
 
-                            // Divide the two NUMs
-                            token::Token::DIV => {
-                                if right_num.unwrap() == 0.0 {
-                                    panic!("Can't divide by 0!");
-                                }
-                                d = create_node_from_num(left_num.unwrap() / right_num.unwrap());
-                            }
+    // Cancelling a VAR (eg. x / x is 1) only holds when it can't be 0, so without that assumption leave the node as it is
+    if divides_var(&data_type, &left, &right, assumptions) {
+        return stock_node(data_type, left, right)
+    }
 
-                            // Raise the NUM to the power of the other NUM (left to right)
-                            token::Token::EXP => {
-                                d = create_node_from_num(left_num.unwrap().powf(right_num.unwrap()));
-                            }
+    // The rules below divide by the NUMs they find, so leave a node that could divide by 0 as it is
+    if divides_zero(&data_type, &left, &right) {
+        return stock_node(data_type, left, right)
+    }
 
-                            // Else return None (shouldn't ever trigger)
-                            _ => {
-                                return None
-                            }
-                        }
-                        return d
-
-                    // *
-                    //          (OP)
-                    //  (VAR)           (VAR)
-                    } else if left_var != None && right_var != None {
-                        let d: Option<Box<Node>>;
-                        match a.data_type {
-
-                            // Add the two VARs (2.0 * VAR)
-                            token::Token::ADD => {
-                                d = Some(Box::new(Node {
-                                    data_type: token::Token::MUL,
-                                    left: create_node_from_num(2.00),
-                                    right: create_node_from_var(left_var.unwrap()),
-                                }));
-                            }
+    // decompose it (i am too lazy for matching)
+    let decomposed: (Option<N>, Option<N>, Option<symbol::Symbol>, Option<symbol::Symbol>, Option<i32>, Option<i32>) = type_declare(&left, &right);
+    let left_num: Option<N> = decomposed.0;
+    let right_num: Option<N> = decomposed.1;
+    let left_var: Option<symbol::Symbol> = decomposed.2;
+    let right_var: Option<symbol::Symbol> = decomposed.3;
+    let left_type: Option<i32> = decomposed.4;
+    let right_type: Option<i32> = decomposed.5;
+
+    // decompose left_left and left_right
+    let left_left_num: Option<N>;
+    let left_right_num: Option<N>;
+    let left_left_var: Option<symbol::Symbol>;
+    let left_right_var: Option<symbol::Symbol>;
+
+    // decompose right_left and right_right
+    let right_left_num: Option<N>;
+    let right_right_num: Option<N>;
+    let right_left_var: Option<symbol::Symbol>;
+    let right_right_var: Option<symbol::Symbol>;
+    let right_right_type: Option<i32>;
+
+    // decompose right_right_left and right_right_right
+    let right_right_left_num: Option<N>;
+    let right_right_right_num: Option<N>;
+    let right_right_left_var: Option<symbol::Symbol>;
+    let right_right_right_var: Option<symbol::Symbol>;
+
+    // Check to see if we must decompose left_left and left_right
+    if left_type != None {
+        let left_decomposed: (Option<N>, Option<N>, Option<symbol::Symbol>, Option<symbol::Symbol>, Option<i32>, Option<i32>) = type_declare(&left.as_ref().unwrap().left, &left.as_ref().unwrap().right);
+        left_left_num = left_decomposed.0;
+        left_right_num = left_decomposed.1;
+        left_left_var = left_decomposed.2;
+        left_right_var = left_decomposed.3;
+
+    // if not, set all to None
+    } else {
+        left_left_num = None;
+        left_right_num = None;
+        left_left_var = None;
+        left_right_var = None;
+    }
 
-                            // Multply the two VARs (VAR ^ 2.0)
-                            token::Token::MUL => {
-                                d = Some(Box::new(Node {
-                                    data_type: token::Token::EXP,
-                                    left: create_node_from_var(left_var.unwrap()),
-                                    right: create_node_from_num(2.00),
-                                }));
-                            }
+    // check to see if we must decompose right_left and right_right
+    if right_type != None {
+        let right_decomposed: (Option<N>, Option<N>, Option<symbol::Symbol>, Option<symbol::Symbol>, Option<i32>, Option<i32>) = type_declare(&right.as_ref().unwrap().left, &right.as_ref().unwrap().right);
+        right_left_num = right_decomposed.0;
+        right_right_num = right_decomposed.1;
+        right_left_var = right_decomposed.2;
+        right_right_var = right_decomposed.3;
+        right_right_type = right_decomposed.5;
+
+        // check to see if we must decompose right_right_left and right_right_right
+        if right_right_type != None {
+            let right_right_decompose: (Option<N>, Option<N>, Option<symbol::Symbol>, Option<symbol::Symbol>, Option<i32>, Option<i32>) = type_declare(&right.as_ref().unwrap().right.as_ref().unwrap().left, &right.as_ref().unwrap().right.as_ref().unwrap().right);
+            right_right_left_num = right_right_decompose.0;
+            right_right_right_num = right_right_decompose.1;
+            right_right_left_var = right_right_decompose.2;
+            right_right_right_var = right_right_decompose.3;
+
+        // if not, set all to none
+        } else {
+            right_right_left_num = None;
+            right_right_right_num = None;
+            right_right_left_var = None;
+            right_right_right_var = None;
+        }
 
-                            // Divide the two VARs (1.00)
-                            token::Token::DIV => {
-                                d = create_node_from_num(1.00);
-                            }
+    // if not, set all to None
+    } else {
+        right_left_num = None;
+        right_right_num = None;
+        right_left_var = None;
+        right_right_var = None;
+        right_right_type = None;
+        right_right_left_num = None;
+        right_right_right_num = None;
+        right_right_left_var = None;
+        right_right_right_var = None;
+    }
 
-                            // Exponent already simplified
-                            token::Token::EXP => {
-                                return stock_node(a.data_type, left, right)
-                            }
+    // *
+    //          (OP)
+    //  (NUM)           (NUM)
+    if left_num != None && right_num != None {
+        let d: Option<Box<Node<N>>>;
+        match data_type {
 
-                            // Else return None (shouldn't ever trigger)
-                            _ => {
-                                return None
-                            }
-                        }
-                        return d
-                        
-                    // * (left vs. right sensitive expression, has to be either DIV or EXP)
-                    //        (OP)
-                    //  (VAR)      (NUM)
-                    } else if left_var != None && right_num != None {
-                        match a.data_type {
-                            // If DIV, set the node to MUL, and Multiply between 1 over right NUM and VAR
-                            //        (EXP or DIV) 
-                            //  (VAR)             (NUM)
-                            //
-                            token::Token::DIV => {
-                                return Some(Box::new(Node {
-                                    data_type: token::Token::MUL,
-                                    left: create_node_from_num(1.0 / right_num.unwrap()),
-                                    right: create_node_from_var(left_var.unwrap()),
-                                }))
-                            }
-                            token::Token::EXP => {
-                                // If right_num is greater than 1.0, then just return the node
-                                if right_num.unwrap() > 1.0 {
-                                    return stock_node(a.data_type, left, right)
-                                // If it is equal to 1.0, just return the VAR
-                                } else if right_num.unwrap() == 0.0  {
-                                    return create_node_from_num(1.0);
-                                } else if right_num.unwrap() == 1.0 {
-                                    return create_node_from_var(left_var.unwrap())
-                                // If it is negative
-                                } else if right_num.unwrap().is_sign_negative() {
-                                    // If equal to -1.0
-                                    if right_num.unwrap() == -1.0 {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: create_node_from_num(1.0),
-                                            right: create_node_from_var(left_var.unwrap()),
-                                        }))
-                                    // If not equal to -1.0
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: create_node_from_num(1.0),
-                                            right: Some(Box::new(Node {
-                                                data_type: token::Token::EXP,
-                                                left: create_node_from_var(left_var.unwrap()),
-                                                right: create_node_from_num(right_num.unwrap().abs()),
-                                            }))
-                                        }))
-                                    }
-                                // If all of the above isn't the case, just set d equal to the node
-                                } else {
-                                    return stock_node(a.data_type, left, right)
-                                }
-                            }
-                            // Else return stock_node(a.data_type, left, right), this should only ever trigger if there is an EXP or DIV, as otherwise NUM would be left and VAR would be right :)
-                            _ => {
-                                return stock_node(a.data_type, left, right)
-                            }
-                        }
+            // Add the two NUMs
+            token::Token::ADD => {
+                d = create_node_from_num(left_num.clone().unwrap() + right_num.clone().unwrap());
+            }
 
-                    // *
-                    //          (OP)
-                    //  (NUM)           (VAR)
-                    } else if left_num != None && right_var != None {
-                        if left_num.unwrap() == 0.00 {
-                            return create_node_from_var(right_var.unwrap())
-                        } else {
-                            return stock_node(a.data_type, left, right)
-                        }
+            // Multiply the two NUMs
+            token::Token::MUL => {
+                d = create_node_from_num(left_num.clone().unwrap() * right_num.clone().unwrap());
+            }
 
-                    // *
-                    //        (OP)
-                    //  (VAR)       (OP)
-                    } else if left_var != None && right_type != None {
-                        // +
-                        //         (MUL or DIV)
-                        // (VAR)          (MUL or DIV)
-                        //           (NUM)            (EXP)
-                        //                       (VAR)     (NUM)
-                        if right_right_type != None && right_right_type.unwrap() == 3 && right_right_left_var != None && right_right_right_num != None {
-                            // if we are multiplying
-                            if right_type.unwrap() == 1 {
-                                match a.data_type {
-                                    // If it is equal to MUL
-                                    token::Token::MUL => {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::MUL,
-                                            left: create_node_from_num(right_left_num.unwrap()),
-                                            right: Some(Box::new(Node {
-                                                data_type: token::Token::EXP,
-                                                left: create_node_from_var(left_var.unwrap()),
-                                                right: create_node_from_num(right_right_right_num.unwrap() + 1.0),
-                                            })),
-                                        }))
-                                    }
-
-                                    // If it is equal to DIV
-                                    token::Token::DIV => {
-                                        // Find new exponent
-                                        let exponent: f32 = right_right_right_num.unwrap() - 1.0;
-                                        // if that exponent is equal to 1, continue
-
-                                        if exponent == 0.0 {
-                                            return create_node_from_num(1.00 / right_left_num.unwrap())
-                                        } else if exponent == 1.00 {
-                                            return Some(Box::new(Node {
-                                                data_type: token::Token::DIV,
-                                                left: create_node_from_num(1.00),
-                                                right: Some(Box::new(Node {
-                                                    data_type: token::Token::MUL,
-                                                    left: create_node_from_num(right_left_num.unwrap()),
-                                                    right: create_node_from_var(left_var.unwrap()),
-                                                }))
-                                            }))
-
-                                        // If not, do the following
-                                        } else {
-                                            return Some(Box::new(Node {
-                                                data_type: token::Token::DIV,
-                                                left: create_node_from_num(1.00),
-                                                right: Some(Box::new(Node {
-                                                    data_type: token::Token::MUL,
-                                                    left: create_node_from_num(right_left_num.unwrap()),
-                                                    right: Some(Box::new(Node {
-                                                        data_type: token::Token::EXP,
-                                                        left: create_node_from_var(left_var.unwrap()),
-                                                        right: create_node_from_num(exponent),
-                                                    }))
-                                                })),
-                                            }))
-                                        }
-                                    }
-
-                                    // Else just return the node
-                                    _ => {return stock_node(a.data_type, left, right)}
-                                }
-                            // if we are dividing
-                            } else if right_type.unwrap() == 2 {
-                                match a.data_type {
-                                    token::Token::MUL => {
-                                        let exponent: f32;
-                                        if right_right_right_num.unwrap() == 1.00 {
-                                            return create_node_from_num(right_left_num.unwrap())
-                                        } else {
-                                            exponent = right_right_right_num.unwrap() - 1.00;
-                                        }
-                                        if exponent != 1.00 {
-                                            return Some(Box::new(Node {
-                                                    data_type: token::Token::DIV,
-                                                    left: create_node_from_num(right_left_num.unwrap()),
-                                                    right: Some(Box::new(Node{
-                                                        data_type: token::Token::EXP,
-                                                        left: create_node_from_var(left_var.unwrap()),
-                                                        right: create_node_from_num(exponent),
-                                                    })),
-                                                }))
-                                        } else {
-                                            return Some(Box::new(Node {
-                                                data_type: token::Token::DIV,
-                                                left: create_node_from_num(right_left_num.unwrap()),
-                                                right: create_node_from_var(left_var.unwrap()),
-                                            }))
-                                        }
-                                    }
-                                    token::Token::DIV => {
-                                        if right_right_right_num.unwrap() == 0.00 {
-                                            return Some(Box::new(Node {
-                                                data_type: token::Token::DIV,
-                                                left: create_node_from_var(left_var.unwrap()),
-                                                right: create_node_from_num(right_left_num.unwrap()),
-                                            }))
-                                        } else {
-                                            return Some(Box::new(Node {
-                                                data_type: token::Token::DIV,
-                                                left: Some(Box::new(Node {
-                                                    data_type: token::Token::EXP,
-                                                    left: create_node_from_var(left_var.unwrap()),
-                                                    right: create_node_from_num(right_right_right_num.unwrap() + 1.00),
-                                                })),
-                                                right: create_node_from_num(right_left_num.unwrap()),
-                                            }))
-                                        }
-                                    }
-                                    _ => {return stock_node(a.data_type, left, right)}
-                                }
-                            } else {
-                                return stock_node(a.data_type, left, right)
-                            }
+            // Divide the two NUMs
+            token::Token::DIV => {
+                d = create_node_from_num(left_num.clone().unwrap() / right_num.clone().unwrap());
+            }
 
-                        // +
-                        //            (OP)
-                        //  (VAR)            (^)
-                        //             (VAR)     (NUM)
-                        } else if right_type.unwrap() == 3 && right_left_var != None && right_right_num != None {
-                            match a.data_type {
-
-                                // +
-                                //            (MUL)
-                                //  (VAR)            (^)
-                                //             (VAR)     (NUM)
-                                token::Token::MUL => {
-                                    return Some(Box::new(Node {
-                                        data_type: token::Token::EXP,
-                                        left: create_node_from_var(left_var.unwrap()),
-                                        right: create_node_from_num(right_right_num.unwrap() + 1.00),
-                                    }))
-                                }
+            // Raise the NUM to the power of the other NUM (left to right)
+            token::Token::EXP => {
+                match left_num.clone().unwrap().pow(&right_num.clone().unwrap()) {
+                    Some(b) => d = create_node_from_num(b),
+                    // An irrational power (eg. 2 ^ 0.5) can't be a NUM, so leave it as it is
+                    None => return stock_node(data_type, left, right),
+                }
+            }
 
-                                // +
-                                //            (DIV)
-                                //  (VAR)            (^)
-                                //             (VAR)     (NUM)
-                                token::Token::DIV => {
-                                    let exponent: f32 = right_right_num.unwrap() - 1.00;
-                                    if exponent == 1.00 {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: create_node_from_num(1.00),
-                                            right: create_node_from_var(left_var.unwrap()),
-                                        }))
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: create_node_from_num(1.00),
-                                            right: Some(Box::new(Node {
-                                                data_type: token::Token::EXP,
-                                                left: create_node_from_var(left_var.unwrap()),
-                                                right: create_node_from_num(exponent),
-                                            }))
-                                        }))
-                                    }
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
-                        
-                        // +
-                        //          (ADD)
-                        //  (VAR)           (MUL)
-                        //          (-1)            (VAR)
-                        } else if right_type.unwrap() == 1 && right_right_var != None && right_left_num != None && right_left_num.unwrap() == -1.0 { 
-                            return create_node_from_num(0.0);
-                        } else {
-                            return stock_node(a.data_type, left, right)
-                        }
+            // Else return None (shouldn't ever trigger)
+            _ => {
+                return None
+            }
+        }
+        return d
+
+    // *
+    //          (OP)
+    //  (VAR)           (VAR)
+    } else if left_var != None && right_var != None && left_var == right_var {
+        let d: Option<Box<Node<N>>>;
+        match data_type {
+
+            // Add the two VARs (2 * VAR)
+            token::Token::ADD => {
+                d = Some(Box::new(Node {
+                    data_type: token::Token::MUL,
+                    left: create_node_from_num(N::from_integer(2)),
+                    right: create_node_from_var(left_var.unwrap()),
+                }));
+            }
 
-                    // * 
-                    //       (OP)
-                    //  (OP)     (VAR)
-                    } else if left_type != None && right_var != None {
-
-                        // +
-                        //               (DIV)
-                        //      (EXP)           (VAR)
-                        // (VAR)     (NUM)
-                        if left_type.unwrap() == 3 && left_left_var != None && left_right_num != None {
-                            match a.data_type {
-                                token::Token::DIV => {
-                                    if left_right_num.unwrap() == 2.00 {
-                                        return create_node_from_var(left_left_var.unwrap())
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::EXP,
-                                            left: create_node_from_var(left_left_var.unwrap()),
-                                            right: create_node_from_num(left_right_num.unwrap() - 1.00),
-                                        }))
-                                    }
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
-                        
-                        // +
-                        //               (DIV)
-                        //       (MUL)           (VAR)
-                        //  (NUM)     (VAR)
-                        } else if left_type.unwrap() == 1 && left_left_num != None && left_right_var != None {
-                            match a.data_type {
-                                token::Token::DIV => {
-                                    return create_node_from_num(left_left_num.unwrap())
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
+            // Multply the two VARs (VAR ^ 2)
+            token::Token::MUL => {
+                d = Some(Box::new(Node {
+                    data_type: token::Token::EXP,
+                    left: create_node_from_var(left_var.unwrap()),
+                    right: create_node_from_num(N::from_integer(2)),
+                }));
+            }
 
-                        } else {
-                            return stock_node(a.data_type, left, right)
-                        }
+            // Divide the two VARs (1)
+            token::Token::DIV => {
+                d = create_node_from_num(N::from_integer(1));
+            }
 
-                    // *
-                    //      (OP)
-                    // (NUM)    (OP)
-                    } else if left_num != None && right_type != None {
-                        // +
-                        //        (OP)
-                        //  (NUM)       (MUL)
-                        //        (NUM)      (VAR)
-                        if right_type.unwrap() == 1 && right_left_num != None && right_right_var != None {
-                            match a.data_type {
-                                token::Token::MUL => {
-                                    return Some(Box::new(Node {
-                                        data_type: token::Token::MUL,
-                                        left: create_node_from_num(left_num.unwrap() * right_left_num.unwrap()),
-                                        right: create_node_from_var(right_right_var.unwrap()),
-                                    }))
-                                }
-                                token::Token::DIV => {
-                                    if left_num.unwrap() == right_left_num.unwrap() {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: create_node_from_num(1.00),
-                                            right: create_node_from_var(right_right_var.unwrap()),
-                                        }))
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: create_node_from_num(left_num.unwrap() * (1.00 / right_left_num.unwrap())),
-                                            right: create_node_from_var(right_right_var.unwrap()),
-                                        }))
-                                    }
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
+            // Exponent already simplified
+            token::Token::EXP => {
+                return stock_node(data_type, left, right)
+            }
 
-                        // +
-                        //         (OP)
-                        //  (NUM)        (DIV)
-                        //         (NUM)       (VAR)
-                        } else if right_type.unwrap() == 2 && right_left_num != None && right_right_var != None {
-                            match a.data_type {
-                                token::Token::DIV => {
-                                    if left_num.unwrap() == right_left_num.unwrap() {
-                                        return create_node_from_var(right_right_var.unwrap())
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: Some(Box::new(Node {
-                                                data_type: token::Token::MUL,
-                                                left: create_node_from_num(left_num.unwrap()),
-                                                right: create_node_from_var(right_right_var.unwrap()),
-                                            })),
-                                            right: create_node_from_num(right_left_num.unwrap()), 
-                                        }))
-                                    }
-                                }
-                                token::Token::MUL => {
-                                    return Some(Box::new(Node {
-                                        data_type: token::Token::DIV,
-                                        left: create_node_from_num(left_num.unwrap() * right_left_num.unwrap()),
-                                        right: create_node_from_var(right_right_var.unwrap()),
-                                    }))
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
+            // Else return None (shouldn't ever trigger)
+            _ => {
+                return None
+            }
+        }
+        return d
+        
+    // * (left vs. right sensitive expression, has to be either DIV or EXP)
+    //        (OP)
+    //  (VAR)      (NUM)
+    } else if left_var != None && right_num != None {
+        match data_type {
+            // If DIV, set the node to MUL, and Multiply between 1 over right NUM and VAR
+            //        (EXP or DIV) 
+            //  (VAR)             (NUM)
+            //
+            token::Token::DIV => {
+                return Some(Box::new(Node {
+                    data_type: token::Token::MUL,
+                    left: create_node_from_num(N::from_integer(1) / right_num.clone().unwrap()),
+                    right: create_node_from_var(left_var.unwrap()),
+                }))
+            }
+            token::Token::EXP => {
+                // If right_num is greater than 1, then just return the node
+                if right_num.clone().unwrap() > N::from_integer(1) {
+                    return stock_node(data_type, left, right)
+                // If it is equal to 1, just return the VAR
+                } else if right_num.clone().unwrap() == N::from_integer(0)  {
+                    return create_node_from_num(N::from_integer(1));
+                } else if right_num.clone().unwrap() == N::from_integer(1) {
+                    return create_node_from_var(left_var.unwrap())
+                // If it is negative
+                } else if right_num.clone().unwrap().is_negative() {
+                    // If equal to -1
+                    if right_num.clone().unwrap() == N::from_integer(-1) {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: create_node_from_num(N::from_integer(1)),
+                            right: create_node_from_var(left_var.unwrap()),
+                        }))
+                    // If not equal to -1
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: create_node_from_num(N::from_integer(1)),
+                            right: Some(Box::new(Node {
+                                data_type: token::Token::EXP,
+                                left: create_node_from_var(left_var.unwrap()),
+                                right: create_node_from_num(right_num.clone().unwrap().abs()),
+                            }))
+                        }))
+                    }
+                // If all of the above isn't the case, just set d equal to the node
+                } else {
+                    return stock_node(data_type, left, right)
+                }
+            }
+            // Else return stock_node(data_type, left, right), this should only ever trigger if there is an EXP or DIV, as otherwise NUM would be left and VAR would be right :)
+            _ => {
+                return stock_node(data_type, left, right)
+            }
+        }
 
-                        // +
-                        //          (OP)
-                        //  (NUM)           (DIV)
-                        //          (VAR)           (NUM)
-                        } else if right_type.unwrap() == 2 && right_left_var != None && right_right_num != None {
-                            match a.data_type {
-                                token::Token::MUL => {
-                                    if right_left_num.unwrap() == right_num.unwrap() {
-                                        return create_node_from_var(left_left_var.unwrap())
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::MUL,
-                                            left: create_node_from_num(right_num.unwrap() / left_right_num.unwrap()),
-                                            right: create_node_from_var(left_left_var.unwrap()),
-                                        }))
-                                    }
-                                }
-                                token::Token::DIV => {
-                                    if left_num.unwrap() == right_right_num.unwrap() {
-                                        return create_node_from_var(right_left_var.unwrap())
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::MUL,
-                                            left: create_node_from_num(left_num.unwrap() / right_right_num.unwrap()),
-                                            right: create_node_from_var(right_left_var.unwrap()),
-                                        }))
-                                    }
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
-                        
-                        // +
-                        //       (OP)
-                        // (NUM)      (EXP)
-                        //       (VAR)      (NUM)
-                        } else if right_type.unwrap() == 3 && right_left_var != None && right_right_num != None {
-                            match a.data_type {
-                                token::Token::ADD => {
-                                    if left_num.unwrap() == 0.00 {
-                                        return right
-                                    } else {
-                                        return stock_node(a.data_type, left, right)
-                                    }
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
+    // *
+    //          (OP)
+    //  (NUM)           (VAR)
+    } else if left_num != None && right_var != None {
+        if matches!(data_type, token::Token::ADD) && left_num.clone().unwrap().is_zero() {
+            return create_node_from_var(right_var.unwrap())
+        } else {
+            return stock_node(data_type, left, right)
+        }
 
-                        // +
-                        //          (OP)
-                        //  (NUM)         (DIV)
-                        //          (NUM)       (MUL)
-                        //                (NUM)       (VAR)
-                        } else if right_type != None && right_type.unwrap() == 2 && right_left_num != None && right_right_type != None && right_right_type.unwrap() == 1 && right_right_left_num != None && right_right_right_var != None {
-                            match a.data_type {
-                                token::Token::MUL => {
-                                    return Some(Box::new(Node {
-                                        data_type: token::Token::DIV,
-                                        left: create_node_from_num(left_num.unwrap() * right_left_num.unwrap()),
-                                        right: Some(Box::new(Node {
-                                            data_type: token::Token::MUL,
-                                            left: create_node_from_num(right_right_left_num.unwrap()),
-                                            right: create_node_from_var(right_right_right_var.unwrap()),
-                                        })),
-                                    }))
-                                }
-                                token::Token::DIV => {
-                                    return Some(Box::new(Node {
-                                        data_type: token::Token::MUL,
-                                        left: create_node_from_num((left_num.unwrap() * right_right_left_num.unwrap()) / right_left_num.unwrap()),
-                                        right: create_node_from_var(right_right_right_var.unwrap()),
-                                    }))
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
-                        
+    // *
+    //        (OP)
+    //  (VAR)       (OP)
+    } else if left_var != None && right_type != None {
+        // +
+        //         (MUL or DIV)
+        // (VAR)          (MUL or DIV)
+        //           (NUM)            (EXP)
+        //                       (VAR)     (NUM)
+        if right_right_type != None && right_right_type.unwrap() == 3 && right_right_left_var != None && right_right_right_num != None && right_right_left_var == left_var {
+            // if we are multiplying
+            if right_type.unwrap() == 1 {
+                match data_type {
+                    // If it is equal to MUL
+                    token::Token::MUL => {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::MUL,
+                            left: create_node_from_num(right_left_num.clone().unwrap()),
+                            right: Some(Box::new(Node {
+                                data_type: token::Token::EXP,
+                                left: create_node_from_var(left_var.unwrap()),
+                                right: create_node_from_num(right_right_right_num.clone().unwrap() + N::from_integer(1)),
+                            })),
+                        }))
+                    }
+
+                    // If it is equal to DIV
+                    token::Token::DIV => {
+                        // Find new exponent
+                        let exponent: N = right_right_right_num.clone().unwrap() - N::from_integer(1);
+                        // if that exponent is equal to 1, continue
+
+                        if exponent == N::from_integer(0) {
+                            return create_node_from_num(N::from_integer(1) / right_left_num.clone().unwrap())
+                        } else if exponent == N::from_integer(1) {
+                            return Some(Box::new(Node {
+                                data_type: token::Token::DIV,
+                                left: create_node_from_num(N::from_integer(1)),
+                                right: Some(Box::new(Node {
+                                    data_type: token::Token::MUL,
+                                    left: create_node_from_num(right_left_num.clone().unwrap()),
+                                    right: create_node_from_var(left_var.unwrap()),
+                                }))
+                            }))
+
+                        // If not, do the following
                         } else {
-                            return stock_node(a.data_type, left, right)
+                            return Some(Box::new(Node {
+                                data_type: token::Token::DIV,
+                                left: create_node_from_num(N::from_integer(1)),
+                                right: Some(Box::new(Node {
+                                    data_type: token::Token::MUL,
+                                    left: create_node_from_num(right_left_num.clone().unwrap()),
+                                    right: Some(Box::new(Node {
+                                        data_type: token::Token::EXP,
+                                        left: create_node_from_var(left_var.unwrap()),
+                                        right: create_node_from_num(exponent),
+                                    }))
+                                })),
+                            }))
                         }
+                    }
 
-                    // *
-                    //       (OP)
-                    //  (OP)      (NUM)
-                    } else if left_type != None && right_num != None {
-                        // +
-                        //                (OP)
-                        //        (MUL)           (NUM)
-                        //  (NUM)       (VAR)
-                        if left_type.unwrap() == 1 && left_left_num != None && left_right_var != None {
-                            match a.data_type {
-                                token::Token::DIV => {
-                                    if left_left_num.unwrap() == right_num.unwrap() {
-                                        return create_node_from_var(left_right_var.unwrap())
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::MUL,
-                                            left: create_node_from_num(left_left_num.unwrap() / right_num.unwrap()),
-                                            right: create_node_from_var(left_right_var.unwrap()),
-                                        }))
-                                    }
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
-                        // +
-                        //                (OP)
-                        //        (DIV)           (NUM)
-                        //  (NUM)       (VAR)
-                        } else if left_type.unwrap() == 2 && left_left_num != None && left_right_var != None {
-                            match a.data_type {
-                                token::Token::DIV => {
-                                    if left_left_num.unwrap() == right_num.unwrap() {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::DIV,
-                                            left: create_node_from_num(1.00),
-                                            right: create_node_from_var(left_right_var.unwrap()),
-                                        }))
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::MUL,
-                                            left: create_node_from_num(left_left_num.unwrap() / right_num.unwrap()),
-                                            right: create_node_from_var(left_right_var.unwrap()),
-                                        }))
-                                    }
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
-                        // +
-                        //                (OP)
-                        //        (DIV)           (NUM)
-                        //  (VAR)       (NUM)
-                        } else if left_type.unwrap() == 2 && left_left_num != None && left_right_num != None {
-                            match a.data_type {
-                                token::Token::DIV => {
-                                    return Some(Box::new(Node {
-                                        data_type: token::Token::DIV,
-                                        left: create_node_from_var(left_left_var.unwrap()),
-                                        right: create_node_from_num(left_right_num.unwrap() * right_num.unwrap()),
-                                    }))
-                                }
-                                
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
+                    // Else just return the node
+                    _ => {return stock_node(data_type, left, right)}
+                }
+            // if we are dividing
+            } else if right_type.unwrap() == 2 {
+                match data_type {
+                    token::Token::MUL => {
+                        let exponent: N;
+                        if right_right_right_num.clone().unwrap() == N::from_integer(1) {
+                            return create_node_from_num(right_left_num.clone().unwrap())
                         } else {
-                            return stock_node(a.data_type, left, right)
+                            exponent = right_right_right_num.clone().unwrap() - N::from_integer(1);
                         }
-                    // *
-                    //      (OP)
-                    //  (OP)    (OP)
-                    } else if left_type != None && right_type != None {
-                        //                (OP)
-                        //      (EXP)              (EXP)
-                        // (VAR)     (NUM)   (VAR)       (NUM)
-                        if left_type.unwrap() == 3 && right_type.unwrap() == 3 && left_left_var != None && left_right_num != None && right_left_var != None && right_right_num != None {
-                            match a.data_type {
-                                token::Token::DIV => {
-                                    if left_right_num.unwrap() == right_right_num.unwrap() {
-                                        return create_node_from_num(1.00)
-                                    } else if left_right_num.unwrap() < right_right_num.unwrap() {
-                                        if left_right_num.unwrap() == 2.00 && right_right_num.unwrap() == 3.00 {
-                                            return Some(Box::new(Node {
-                                                data_type: token::Token::DIV,
-                                                left: create_node_from_num(1.00),
-                                                right: create_node_from_var(left_left_var.unwrap()),
-                                            }))
-                                        } else {
-                                            return Some(Box::new(Node {
-                                                data_type: token::Token::DIV,
-                                                left: create_node_from_num(1.00),
-                                                right: Some(Box::new(Node {
-                                                    data_type: token::Token::EXP,
-                                                    left: create_node_from_var(left_left_var.unwrap()),
-                                                    right: create_node_from_num(right_right_num.unwrap() - left_right_num.unwrap()),
-                                                }))
-                                            }))
-                                        }
-                                    } else {
-                                        return Some(Box::new(Node {
-                                            data_type: token::Token::EXP,
-                                            left: create_node_from_var(left_left_var.unwrap()),
-                                            right: create_node_from_num(left_right_num.unwrap() - right_right_num.unwrap()),
-                                        }))
-                                    }
-                                }
-                                token::Token::MUL => {
-                                    return Some(Box::new(Node {
+                        if exponent != N::from_integer(1) {
+                            return Some(Box::new(Node {
+                                    data_type: token::Token::DIV,
+                                    left: create_node_from_num(right_left_num.clone().unwrap()),
+                                    right: Some(Box::new(Node{
                                         data_type: token::Token::EXP,
-                                        left: create_node_from_var(left_left_var.unwrap()),
-                                        right: create_node_from_num(left_right_num.unwrap() + right_right_num.unwrap()),
-                                    }))
-                                }
-                                _ => {return stock_node(a.data_type, left, right)}
-                            }
+                                        left: create_node_from_var(left_var.unwrap()),
+                                        right: create_node_from_num(exponent),
+                                    })),
+                                }))
+                        } else {
+                            return Some(Box::new(Node {
+                                data_type: token::Token::DIV,
+                                left: create_node_from_num(right_left_num.clone().unwrap()),
+                                right: create_node_from_var(left_var.unwrap()),
+                            }))
+                        }
+                    }
+                    token::Token::DIV => {
+                        if right_right_right_num.clone().unwrap() == N::from_integer(0) {
+                            return Some(Box::new(Node {
+                                data_type: token::Token::DIV,
+                                left: create_node_from_var(left_var.unwrap()),
+                                right: create_node_from_num(right_left_num.clone().unwrap()),
+                            }))
                         } else {
-                            return stock_node(a.data_type, left, right)
+                            return Some(Box::new(Node {
+                                data_type: token::Token::DIV,
+                                left: Some(Box::new(Node {
+                                    data_type: token::Token::EXP,
+                                    left: create_node_from_var(left_var.unwrap()),
+                                    right: create_node_from_num(right_right_right_num.clone().unwrap() + N::from_integer(1)),
+                                })),
+                                right: create_node_from_num(right_left_num.clone().unwrap()),
+                            }))
                         }
+                    }
+                    _ => {return stock_node(data_type, left, right)}
+                }
+            } else {
+                return stock_node(data_type, left, right)
+            }
 
+        // +
+        //            (OP)
+        //  (VAR)            (^)
+        //             (VAR)     (NUM)
+        } else if right_type.unwrap() == 3 && right_left_var != None && right_right_num != None && right_left_var == left_var {
+            match data_type {
+
+                // +
+                //            (MUL)
+                //  (VAR)            (^)
+                //             (VAR)     (NUM)
+                token::Token::MUL => {
+                    return Some(Box::new(Node {
+                        data_type: token::Token::EXP,
+                        left: create_node_from_var(left_var.unwrap()),
+                        right: create_node_from_num(right_right_num.clone().unwrap() + N::from_integer(1)),
+                    }))
+                }
 
+                // +
+                //            (DIV)
+                //  (VAR)            (^)
+                //             (VAR)     (NUM)
+                token::Token::DIV => {
+                    let exponent: N = right_right_num.clone().unwrap() - N::from_integer(1);
+                    if exponent == N::from_integer(1) {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: create_node_from_num(N::from_integer(1)),
+                            right: create_node_from_var(left_var.unwrap()),
+                        }))
                     } else {
-                        return stock_node(a.data_type, left, right)
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: create_node_from_num(N::from_integer(1)),
+                            right: Some(Box::new(Node {
+                                data_type: token::Token::EXP,
+                                left: create_node_from_var(left_var.unwrap()),
+                                right: create_node_from_num(exponent),
+                            }))
+                        }))
                     }
-               } 
+                }
+                _ => {return stock_node(data_type, left, right)}
             }
+        
+        // +
+        //          (ADD)
+        //  (VAR)           (MUL)
+        //          (-1)            (VAR)
+        } else if matches!(data_type, token::Token::ADD) && right_type.unwrap() == 1 && right_right_var != None && right_left_num != None && right_left_num.clone().unwrap() == N::from_integer(-1) && right_right_var == left_var {
+            return create_node_from_num(N::from_integer(0));
+        } else {
+            return stock_node(data_type, left, right)
         }
-        None => {return node}
-    }
-}
-
-// Splits a vector into branches
-fn vector_split(mut token_vector: Vec<token::Token>, split_location: i32) -> (Vec<token::Token>, Vec<(i32, i32)>, Vec<token::Token>, Vec<(i32, i32)>) {
-    // let the right branch be the split off branch, including split location
-    let mut right_branch: Vec< token::Token> = token_vector.split_off(split_location as usize);
-
-    // fix right, if need be
-    let a: (Vec<token::Token>, Vec<(i32, i32)>) = token::fix_groups(right_branch);
-
-    // declare fixed stuff
-    right_branch = a.0;
-
-    // useless currently, empty assignment
-    let _ = a.1;
-
-    // fix left, if need be
-    let b: (Vec<token::Token>, Vec<(i32, i32)>) = token::fix_groups(token_vector);
 
-    // declare fixed stuff
-    token_vector = b.0;
-    let left_group_locations: Vec<(i32, i32)> = b.1;
+    // * 
+    //       (OP)
+    //  (OP)     (VAR)
+    } else if left_type != None && right_var != None {
+
+        // +
+        //               (DIV)
+        //      (EXP)           (VAR)
+        // (VAR)     (NUM)
+        if left_type.unwrap() == 3 && left_left_var != None && left_right_num != None && left_left_var == right_var {
+            match data_type {
+                token::Token::DIV => {
+                    if left_right_num.clone().unwrap() == N::from_integer(2) {
+                        return create_node_from_var(left_left_var.unwrap())
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::EXP,
+                            left: create_node_from_var(left_left_var.unwrap()),
+                            right: create_node_from_num(left_right_num.clone().unwrap() - N::from_integer(1)),
+                        }))
+                    }
+                }
+                _ => {return stock_node(data_type, left, right)}
+            }
+        
+        // +
+        //               (DIV)
+        //       (MUL)           (VAR)
+        //  (NUM)     (VAR)
+        } else if left_type.unwrap() == 1 && left_left_num != None && left_right_var != None && left_right_var == right_var {
+            match data_type {
+                token::Token::DIV => {
+                    return create_node_from_num(left_left_num.clone().unwrap())
+                }
+                _ => {return stock_node(data_type, left, right)}
+            }
 
-    // look through right branch once more, if it isn't a LGROUP value, delete it
-    match right_branch[0] {
-        token::Token::LGROUP => {}
-        _ => {
-            let _ = right_branch.remove(0 as usize);
+        } else {
+            return stock_node(data_type, left, right)
         }
-    }
-
-    // fix right branch once more
-    let c: (Vec<token::Token>, Vec<(i32, i32)>) = token::fix_groups(right_branch);
-    right_branch = c.0;
-    let right_group_locations = c.1;
-
-    // return it!
-    return (token_vector, left_group_locations, right_branch, right_group_locations)
-}
 
-// Locate splits, return them as a tuple
-fn split_locater(token_vector: Vec<token::Token>, group_locations: Vec<(i32, i32)>) -> (Vec<token::Token>, Vec<(i32, i32)>, Vec<token::Token>, Vec<(i32, i32)>, token::Token) {
-    // Declare conditions
-    let mut add_condition: Option<i32> = None;
-    let mut div_condition: Option<i32> = None;
-    let mut mul_condition: Option<i32> = None;
-    let mut exp_condition: Option<i32> = None;
-    let mut pass_bool: bool = false;
-
-    // Declare return values, including branches, groups, and data type
-    let data_type: token::Token;
-    let left_branch: Vec<token::Token>;
-    let left_group_locations: Vec<(i32, i32)>;
-    let right_branch: Vec<token::Token>;
-    let right_group_locations: Vec<(i32, i32)>;
-
-    // If the token_vector is length 1, it always has a VAR or NUM value, so just return that as a node, with empty left and rights and such
-    if token_vector.len() == 1 {
-        match token_vector[0] {
-            _ => {
-                left_branch = Vec::new();
-                left_group_locations = Vec::new();
-                right_branch = Vec::new();
-                right_group_locations = Vec::new();
-                return (left_branch, left_group_locations, right_branch, right_group_locations, token_vector[0].clone());
+    // *
+    //      (OP)
+    // (NUM)    (OP)
+    } else if left_num != None && right_type != None {
+        // +
+        //        (OP)
+        //  (NUM)       (MUL)
+        //        (NUM)      (VAR)
+        if right_type.unwrap() == 1 && right_left_num != None && right_right_var != None {
+            match data_type {
+                token::Token::MUL => {
+                    return Some(Box::new(Node {
+                        data_type: token::Token::MUL,
+                        left: create_node_from_num(left_num.clone().unwrap() * right_left_num.clone().unwrap()),
+                        right: create_node_from_var(right_right_var.unwrap()),
+                    }))
+                }
+                token::Token::DIV => {
+                    if left_num.clone().unwrap() == right_left_num.clone().unwrap() {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: create_node_from_num(N::from_integer(1)),
+                            right: create_node_from_var(right_right_var.unwrap()),
+                        }))
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: create_node_from_num(left_num.clone().unwrap() * (N::from_integer(1) / right_left_num.clone().unwrap())),
+                            right: create_node_from_var(right_right_var.unwrap()),
+                        }))
+                    }
+                }
+                _ => {return stock_node(data_type, left, right)}
             }
-        }
-    // else, continue
-    } else {
-        // for a in the token_vector length
-        for a in 0..token_vector.len() as i32 {
 
-            // Find each right thing, marking them down, if they are located in parenthesis pass them. 
-            // These do represent the weightings as well, but these could've been written in any order.
-            match token_vector[a as usize] {
-                token::Token::ADD => {
-                    for b in &group_locations {
-                        if a > b.0 && a < b.1 {
-                            pass_bool = true;
-                            break;
-                        }
+        // +
+        //         (OP)
+        //  (NUM)        (DIV)
+        //         (NUM)       (VAR)
+        } else if right_type.unwrap() == 2 && right_left_num != None && right_right_var != None {
+            match data_type {
+                token::Token::DIV => {
+                    if left_num.clone().unwrap() == right_left_num.clone().unwrap() {
+                        return create_node_from_var(right_right_var.unwrap())
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: Some(Box::new(Node {
+                                data_type: token::Token::MUL,
+                                left: create_node_from_num(left_num.clone().unwrap()),
+                                right: create_node_from_var(right_right_var.unwrap()),
+                            })),
+                            right: create_node_from_num(right_left_num.clone().unwrap()), 
+                        }))
                     }
-                    if pass_bool == false {
-                        add_condition = Some(a);
+                }
+                token::Token::MUL => {
+                    return Some(Box::new(Node {
+                        data_type: token::Token::DIV,
+                        left: create_node_from_num(left_num.clone().unwrap() * right_left_num.clone().unwrap()),
+                        right: create_node_from_var(right_right_var.unwrap()),
+                    }))
+                }
+                _ => {return stock_node(data_type, left, right)}
+            }
+
+        // +
+        //          (OP)
+        //  (NUM)           (DIV)
+        //          (VAR)           (NUM)
+        } else if right_type.unwrap() == 2 && right_left_var != None && right_right_num != None {
+            match data_type {
+                token::Token::MUL => {
+                    if right_left_num.clone().unwrap() == right_num.clone().unwrap() {
+                        return create_node_from_var(left_left_var.unwrap())
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::MUL,
+                            left: create_node_from_num(right_num.clone().unwrap() / left_right_num.clone().unwrap()),
+                            right: create_node_from_var(left_left_var.unwrap()),
+                        }))
                     }
-                    pass_bool = false;
                 }
                 token::Token::DIV => {
-                    for b in &group_locations {
-                        if a > b.0 && a < b.1 {
-                            pass_bool = true;
-                            break;
-                        }
+                    if left_num.clone().unwrap() == right_right_num.clone().unwrap() {
+                        return create_node_from_var(right_left_var.unwrap())
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::MUL,
+                            left: create_node_from_num(left_num.clone().unwrap() / right_right_num.clone().unwrap()),
+                            right: create_node_from_var(right_left_var.unwrap()),
+                        }))
                     }
-                    if pass_bool == false {
-                        div_condition = Some(a);
+                }
+                _ => {return stock_node(data_type, left, right)}
+            }
+        
+        // +
+        //       (OP)
+        // (NUM)      (EXP)
+        //       (VAR)      (NUM)
+        } else if right_type.unwrap() == 3 && right_left_var != None && right_right_num != None {
+            match data_type {
+                token::Token::ADD => {
+                    if left_num.clone().unwrap() == N::from_integer(0) {
+                        return right
+                    } else {
+                        return stock_node(data_type, left, right)
                     }
-                    pass_bool = false;
                 }
+                _ => {return stock_node(data_type, left, right)}
+            }
+
+        // +
+        //          (OP)
+        //  (NUM)         (DIV)
+        //          (NUM)       (MUL)
+        //                (NUM)       (VAR)
+        } else if right_type != None && right_type.unwrap() == 2 && right_left_num != None && right_right_type != None && right_right_type.unwrap() == 1 && right_right_left_num != None && right_right_right_var != None {
+            match data_type {
                 token::Token::MUL => {
-                    for b in &group_locations {
-                        if a > b.0 && a < b.1 {
-                            pass_bool = true;
-                            break;
-                        }
-                    }
-                    if pass_bool == false {
-                        mul_condition = Some(a);
-                    }
-                    pass_bool = false;
+                    return Some(Box::new(Node {
+                        data_type: token::Token::DIV,
+                        left: create_node_from_num(left_num.clone().unwrap() * right_left_num.clone().unwrap()),
+                        right: Some(Box::new(Node {
+                            data_type: token::Token::MUL,
+                            left: create_node_from_num(right_right_left_num.clone().unwrap()),
+                            right: create_node_from_var(right_right_right_var.unwrap()),
+                        })),
+                    }))
                 }
-                token::Token::EXP => {
-                    for b in &group_locations {
-                        if a > b.0 && a < b.1 {
-                            pass_bool = true;
-                            break;
-                        }
-                    }
-                    if pass_bool == false {
-                        exp_condition = Some(a);
-                    }
-                    pass_bool = false;
+                token::Token::DIV => {
+                    return Some(Box::new(Node {
+                        data_type: token::Token::MUL,
+                        left: create_node_from_num((left_num.clone().unwrap() * right_right_left_num.clone().unwrap()) / right_left_num.clone().unwrap()),
+                        right: create_node_from_var(right_right_right_var.unwrap()),
+                    }))
                 }
-                _ => {}
+                _ => {return stock_node(data_type, left, right)}
             }
+        
+        } else {
+            return stock_node(data_type, left, right)
         }
-    }
 
-    // Declare the splits
-    let splits;
-
-    // Match each one according to the weight, and if they exist, just return them. This system ensures proper reverse PEMDAS weighting. 
-    match add_condition {
-        Some(_) => {
-            data_type = token::Token::ADD;
-            splits = vector_split(token_vector, add_condition.unwrap());
-            left_branch = splits.0;
-            left_group_locations = splits.1;
-            right_branch = splits.2;
-            right_group_locations = splits.3;
-            return (left_branch, left_group_locations, right_branch, right_group_locations, data_type);
+    // *
+    //       (OP)
+    //  (OP)      (NUM)
+    } else if left_type != None && right_num != None {
+        // +
+        //                (OP)
+        //        (MUL)           (NUM)
+        //  (NUM)       (VAR)
+        if left_type.unwrap() == 1 && left_left_num != None && left_right_var != None {
+            match data_type {
+                token::Token::DIV => {
+                    if left_left_num.clone().unwrap() == right_num.clone().unwrap() {
+                        return create_node_from_var(left_right_var.unwrap())
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::MUL,
+                            left: create_node_from_num(left_left_num.clone().unwrap() / right_num.clone().unwrap()),
+                            right: create_node_from_var(left_right_var.unwrap()),
+                        }))
+                    }
+                }
+                _ => {return stock_node(data_type, left, right)}
+            }
+        // +
+        //                (OP)
+        //        (DIV)           (NUM)
+        //  (NUM)       (VAR)
+        } else if left_type.unwrap() == 2 && left_left_num != None && left_right_var != None {
+            match data_type {
+                token::Token::DIV => {
+                    if left_left_num.clone().unwrap() == right_num.clone().unwrap() {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::DIV,
+                            left: create_node_from_num(N::from_integer(1)),
+                            right: create_node_from_var(left_right_var.unwrap()),
+                        }))
+                    } else {
+                        return Some(Box::new(Node {
+                            data_type: token::Token::MUL,
+                            left: create_node_from_num(left_left_num.clone().unwrap() / right_num.clone().unwrap()),
+                            right: create_node_from_var(left_right_var.unwrap()),
+                        }))
+                    }
+                }
+                _ => {return stock_node(data_type, left, right)}
+            }
+        // +
+        //                (OP)
+        //        (DIV)           (NUM)
+        //  (VAR)       (NUM)
+        } else if left_type.unwrap() == 2 && left_left_num != None && left_right_num != None {
+            match data_type {
+                token::Token::DIV => {
+                    return Some(Box::new(Node {
+                        data_type: token::Token::DIV,
+                        left: create_node_from_var(left_left_var.unwrap()),
+                        right: create_node_from_num(left_right_num.clone().unwrap() * right_num.clone().unwrap()),
+                    }))
+                }
+                
+                _ => {return stock_node(data_type, left, right)}
+            }
+        } else {
+            return stock_node(data_type, left, right)
         }
-        None => {}
-    }
-    // Because MUL and DIV are actually weighted the same, we must locate the right most of each. I opted to look for this within the mul_condition match, by first matching mul_condition
-    // matching div_condition, and then if both are true, comparing their weightings. This ensures that we never unwrap a None value, and it also will return the proper weighting.
-    match mul_condition {
-        Some(_) => {
-            match div_condition {
-                Some(_) => {
-                    if mul_condition.unwrap() > div_condition.unwrap() {
-                        data_type = token::Token::MUL;
-                        splits = vector_split(token_vector, mul_condition.unwrap());
-                        left_branch = splits.0;
-                        left_group_locations = splits.1;
-                        right_branch = splits.2;
-                        right_group_locations = splits.3;
-                        return (left_branch, left_group_locations, right_branch, right_group_locations, data_type);
+    // *
+    //      (OP)
+    //  (OP)    (OP)
+    } else if left_type != None && right_type != None {
+        //                (OP)
+        //      (EXP)              (EXP)
+        // (VAR)     (NUM)   (VAR)       (NUM)
+        if left_type.unwrap() == 3 && right_type.unwrap() == 3 && left_left_var != None && left_right_num != None && right_left_var != None && right_right_num != None && left_left_var == right_left_var {
+            match data_type {
+                token::Token::DIV => {
+                    if left_right_num.clone().unwrap() == right_right_num.clone().unwrap() {
+                        return create_node_from_num(N::from_integer(1))
+                    } else if left_right_num.clone().unwrap() < right_right_num.clone().unwrap() {
+                        if left_right_num.clone().unwrap() == N::from_integer(2) && right_right_num.clone().unwrap() == N::from_integer(3) {
+                            return Some(Box::new(Node {
+                                data_type: token::Token::DIV,
+                                left: create_node_from_num(N::from_integer(1)),
+                                right: create_node_from_var(left_left_var.unwrap()),
+                            }))
+                        } else {
+                            return Some(Box::new(Node {
+                                data_type: token::Token::DIV,
+                                left: create_node_from_num(N::from_integer(1)),
+                                right: Some(Box::new(Node {
+                                    data_type: token::Token::EXP,
+                                    left: create_node_from_var(left_left_var.unwrap()),
+                                    right: create_node_from_num(right_right_num.clone().unwrap() - left_right_num.clone().unwrap()),
+                                }))
+                            }))
+                        }
                     } else {
-                        data_type = token::Token::DIV;
-                        splits = vector_split(token_vector, div_condition.unwrap());
-                        left_branch = splits.0;
-                        left_group_locations = splits.1;
-                        right_branch = splits.2;
-                        right_group_locations = splits.3;
-                        return (left_branch, left_group_locations, right_branch, right_group_locations, data_type);
+                        return Some(Box::new(Node {
+                            data_type: token::Token::EXP,
+                            left: create_node_from_var(left_left_var.unwrap()),
+                            right: create_node_from_num(left_right_num.clone().unwrap() - right_right_num.clone().unwrap()),
+                        }))
                     }
                 }
-                None => {
-                    data_type = token::Token::MUL;
-                    splits = vector_split(token_vector, mul_condition.unwrap());
-                    left_branch = splits.0;
-                    left_group_locations = splits.1;
-                    right_branch = splits.2;
-                    right_group_locations = splits.3;
-                    return (left_branch, left_group_locations, right_branch, right_group_locations, data_type);
+                token::Token::MUL => {
+                    return Some(Box::new(Node {
+                        data_type: token::Token::EXP,
+                        left: create_node_from_var(left_left_var.unwrap()),
+                        right: create_node_from_num(left_right_num.clone().unwrap() + right_right_num.clone().unwrap()),
+                    }))
                 }
+                _ => {return stock_node(data_type, left, right)}
             }
+        } else {
+            return stock_node(data_type, left, right)
         }
-        None => {}
+
+
+    } else {
+        return stock_node(data_type, left, right)
     }
-    match div_condition {
-        Some(_) => {
-            data_type = token::Token::DIV;
-            splits = vector_split(token_vector, div_condition.unwrap());
-            left_branch = splits.0;
-            left_group_locations = splits.1;
-            right_branch = splits.2;
-            right_group_locations = splits.3;
-            return (left_branch, left_group_locations, right_branch, right_group_locations, data_type);
-        }
-        None => {}
+}
+
+// Lookups over a fixed token vector, built once so that each split can be found without rescanning or copying the vector.
+struct SplitTable {
+    // For each LGROUP, the location of its RGROUP.
+    group_ends: Vec<usize>,
+    // For each location, the right most ADD, MUL or DIV, and EXP before it on the same grouping level. The location
+    // of an RGROUP (or the end of the vector) holds the values of the group it closes.
+    previous: Vec<[Option<usize>; 3]>,
+}
+
+// Build the SplitTable in a single pass, keeping a stack of the right most operators for each open group.
+fn split_table<N: Numeric>(token_vector: &[token::Token<N>], group_locations: &[(i32, i32)]) -> SplitTable {
+    let mut group_ends: Vec<usize> = vec![0; token_vector.len()];
+    for a in group_locations {
+        group_ends[a.0 as usize] = a.1 as usize;
     }
-    match exp_condition {
-        Some(_) => {
-            data_type = token::Token::EXP;
-            splits = vector_split(token_vector, exp_condition.unwrap());
-            left_branch = splits.0;
-            left_group_locations = splits.1;
-            right_branch = splits.2;
-            right_group_locations = splits.3;
-            return (left_branch, left_group_locations, right_branch, right_group_locations, data_type);
-        }
-        // Rust wants to see a return even here. This will never run if things go as they should.
-        None => {
-            data_type = token::Token::EXP;
-            splits = vector_split(token_vector, 0);
-            left_branch = splits.0;
-            left_group_locations = splits.1;
-            right_branch = splits.2;
-            right_group_locations = splits.3;
-            return (left_branch, left_group_locations, right_branch, right_group_locations, data_type);
+
+    let mut previous: Vec<[Option<usize>; 3]> = Vec::with_capacity(token_vector.len() + 1);
+    let mut levels: Vec<[Option<usize>; 3]> = vec![[None; 3]];
+    for (i, x) in token_vector.iter().enumerate() {
+        let level: &mut [Option<usize>; 3] = levels.last_mut().unwrap();
+        previous.push(*level);
+        match x {
+            token::Token::ADD => level[0] = Some(i),
+            token::Token::MUL | token::Token::DIV => level[1] = Some(i),
+            token::Token::EXP => level[2] = Some(i),
+            token::Token::LGROUP => levels.push([None; 3]),
+            token::Token::RGROUP => previous[i] = levels.pop().unwrap(),
+            _ => {}
         }
     }
-
+    previous.push(levels[0]);
+    return SplitTable {
+        group_ends: group_ends,
+        previous: previous,
+    }
 }
 
-// The actual creation of a node, including logic to determine left and right weighting.
-fn node_creation(raw_node: (Vec<token::Token>, Vec<(i32, i32)>, Vec<token::Token>, Vec<(i32, i32)>, token::Token)) -> Option<Box<Node>> {
-    let left_branch: Vec<token::Token> = raw_node.0;
-    let left_group_locations: Vec<(i32, i32)> = raw_node.1;
-    let right_branch: Vec<token::Token> = raw_node.2;
-    let right_group_locations: Vec<(i32, i32)> = raw_node.3;
-    let data_type_node: token::Token = raw_node.4;
-    let a: Node;
-
-    // If both of the branches are empty (eg. this is a VAR or NUM) just return this as a complete node (left and rights are empty.)
-    if left_branch.is_empty() && right_branch.is_empty() {
-        a = Node {
-            data_type: data_type_node,
-            left: None,
-            right: None,
-        };
+// The (start, end) of a branch, and the location and data type of its split, if it has one.
+type Split<N> = (usize, usize, Option<(usize, token::Token<N>)>);
 
-    // If this isn't the case, we need to make sure we find what goes where, left vs. right. The weighting for this operation is as follows:
-    // - If there are two NUM, lesser NUM goes to the left.
-    // - If there is NUM and VAR, NUM goes to the left.
-    // - If there is NUM and ABSTRACT (MUL, DIV, etc.), NUM goes to the left.
-    // - If there is VAR and ABSTRACT (MUL, DIV, etc.), VAR goes to the left.
-    // - SPECIAL CASE: If the data_type_node is EXP, then ignore all the above.
-    // - SPECIAL CASE: If the data_type_node is DIV, then ignore all the above.
-    } else {
-        match data_type_node {
-            token::Token::EXP => {
-                a = Node {
-                    data_type: data_type_node,
-                    left: node_creation(split_locater(left_branch, left_group_locations)),
-                    right: node_creation(split_locater(right_branch, right_group_locations)),
-                };
-                return Some(Box::new(a));
-            }
-            token::Token::DIV => {
-                a = Node {
-                    data_type: data_type_node,
-                    left: node_creation(split_locater(left_branch, left_group_locations)),
-                    right: node_creation(split_locater(right_branch, right_group_locations)),
-                };
-                return Some(Box::new(a));
-            }
-            _ => {}
+// Locate the split of the branch between start and end (end exclusive), returning the (start, end) of the branch without any useless groups,
+// as well as the location and data type of the split. A branch without a split is a single VAR or NUM value.
+fn split_locater<N: Numeric>(token_vector: &[token::Token<N>], table: &SplitTable, mut start: usize, mut end: usize) -> Result<Split<N>, ParseError> {
+    // Remove any groups that wrap the entire branch
+    while end - start > 1 && matches!(token_vector[start], token::Token::LGROUP) && table.group_ends[start] == end - 1 {
+        start += 1;
+        end -= 1;
+    }
+    if start >= end {
+        return Err(ParseError::ExpectedValue)
+    }
+
+    // The right most operator on this grouping level, but only if it is inside of the branch.
+    let split = |weight: usize| -> Option<usize> {
+        return table.previous[end][weight].filter(|a| *a >= start)
+    };
+
+    // Match each one according to the weight, and if they exist, just return them. This system ensures proper reverse PEMDAS weighting.
+    // MUL and DIV are weighted the same, so the table already keeps track of the right most of either.
+    for weight in 0..3 {
+        if let Some(a) = split(weight) {
+            return Ok((start, end, Some((a, token_vector[a].clone()))))
         }
+    }
+    if end - start != 1 {
+        return Err(ParseError::ExpectedOperator)
+    }
+    match token_vector[start] {
+        token::Token::NUM(_) | token::Token::VAR(_) => return Ok((start, end, None)),
+        _ => return Err(ParseError::ExpectedValue),
+    }
+}
 
-        // Declare all needed variables for this operation
-        // Raw branches, haven't been determined if they are left and right yet, and recursive, thus the branches won't be worked on till' their value is known
-        let first_branch_raw: Option<Box<Node>> = node_creation(split_locater(left_branch, left_group_locations));
-        let second_branch_raw: Option<Box<Node>> = node_creation(split_locater(right_branch, right_group_locations));
-
-        // left and right processed, taken from first and second branch but determined placement.
-        let left_branch_processed: Option<Box<Node>>;
-        let right_branch_processed: Option<Box<Node>>;
-
-        // number and a bool to say if it exists or not, as well as an Option float. The option is neccesary for the decleration of the number, even if it exists or not.
-        let first_num: Option<f32>;
-        let first_num_bool: bool;
-        let second_num: Option<f32>;
-        let second_num_bool: bool;
-
-        // if a variable is the data_type in either first or second branch.
-        let first_variable: bool;
-        let second_variable: bool;
-
-        // Match first data_type
-        match first_branch_raw.clone().unwrap().data_type {
-            token::Token::NUM(a) => {
-                first_num = Some(a);
-                first_num_bool = true;
-                first_variable = false;
-            }
-            token::Token::VAR(_) => {
-                first_variable = true;
-                first_num_bool = false;
-                first_num = None;
-            }
-            _ => {
-                first_variable = false;
-                first_num_bool = false;
-                first_num = None;
-            }
+// A step of building a tree: build the branch between start and end, or join the last branches built with an operator. A DIV or EXP
+// joins the last two, and a sum or product joins every operand along it.
+enum Build<N> {
+    Branch(usize, usize),
+    Join(token::Token<N>, usize),
+}
+
+// The locations of every ADD (or MUL) splitting a sum (or product), from right to left, given the location of its right most one. The
+// table already has the one before each location on the same grouping level, and a product ends at a DIV, which binds the same as a MUL
+// so the DIV and everything left of it is one operand, eg. the x / y of x / y * z.
+fn chain_splits<N: Numeric>(token_vector: &[token::Token<N>], table: &SplitTable, start: usize, last: usize) -> Vec<usize> {
+    let weight: usize = if matches!(token_vector[last], token::Token::ADD) { 0 } else { 1 };
+    let mut splits: Vec<usize> = vec![last];
+    while let Some(a) = table.previous[*splits.last().unwrap()][weight].filter(|a| *a >= start) {
+        if token_vector[a] != token_vector[last] {
+            break;
         }
+        splits.push(a);
+    }
+    return splits
+}
 
-        // match second data_type
-        match second_branch_raw.clone().unwrap().data_type {
-            token::Token::NUM(a) => {
-                second_num = Some(a);
-                second_num_bool = true;
-                second_variable = false;
-            }
-            token::Token::VAR(_) => {
-                second_variable = true;
-                second_num_bool = false;
-                second_num = None;
+// The actual creation of a node, including logic to determine left and right weighting. The branches are built with a stack of steps
+// rather than recursively, since a long sum or product is a chain as deep as it is long (a + b + c is (a + b) + c.)
+fn node_creation<N: Numeric>(token_vector: &[token::Token<N>], table: &SplitTable, start: usize, end: usize) -> Result<Box<Node<N>>, ParseError> {
+    let mut steps: Vec<Build<N>> = vec![Build::Branch(start, end)];
+    let mut built: Vec<Box<Node<N>>> = Vec::new();
+    while let Some(step) = steps.pop() {
+        match step {
+            Build::Branch(start, end) => {
+                let raw_node: (usize, usize, Option<(usize, token::Token<N>)>) = split_locater(token_vector, table, start, end)?;
+                match raw_node.2 {
+                    // If there is no split (eg. this is a VAR or NUM) this is a complete node (left and rights are empty.)
+                    None => built.push(Box::new(Node {
+                        data_type: token_vector[raw_node.0].clone(),
+                        left: None,
+                        right: None,
+                    })),
+                    // A sum or product is split at every ADD or MUL along it, and so is any sum or product grouped inside of it, so that
+                    // x * (y * z) is built the same as x * y * z. Its operands are built from left to right.
+                    Some((split_location, data_type_node)) if matches!(data_type_node, token::Token::ADD | token::Token::MUL) => {
+                        let mut chains: Vec<(usize, usize, usize)> = vec![(raw_node.0, raw_node.1, split_location)];
+                        let mut operands: Vec<(usize, usize)> = Vec::new();
+                        while let Some((start, mut end, last)) = chains.pop() {
+                            let mut ranges: Vec<(usize, usize)> = Vec::new();
+                            for a in chain_splits(token_vector, table, start, last) {
+                                ranges.push((a + 1, end));
+                                end = a;
+                            }
+                            ranges.push((start, end));
+                            for (start, end) in ranges {
+                                match split_locater(token_vector, table, start, end)? {
+                                    (start, end, Some((a, inner))) if inner == data_type_node => chains.push((start, end, a)),
+                                    _ => operands.push((start, end)),
+                                }
+                            }
+                        }
+                        steps.push(Build::Join(data_type_node, operands.len()));
+                        steps.extend(operands.into_iter().map(|(start, end)| Build::Branch(start, end)));
+                    }
+                    // If this isn't the case, the branches go on either side of the split, the left one is built first.
+                    Some((split_location, data_type_node)) => {
+                        steps.push(Build::Join(data_type_node, 2));
+                        steps.push(Build::Branch(split_location + 1, raw_node.1));
+                        steps.push(Build::Branch(raw_node.0, split_location));
+                    }
+                }
             }
-            _ => {
-                second_variable = false;
-                second_num_bool = false;
-                second_num = None;
+            // ADD and MUL don't care about the order of their operands, so the operands of a sum or product are sorted into the
+            // canonical order (see order.rs) and chained to the left, eg. a NUM goes to the left of a VAR, and a VAR to the left of an
+            // ABSTRACT (MUL, DIV, etc.) So the tree of a sum or product doesn't depend on the order it was written in, eg. x * y * z and
+            // z * y * x build the same tree. EXP and DIV keep their branches where they are.
+            Build::Join(data_type_node, count) => {
+                let branches: Vec<Box<Node<N>>> = built.split_off(built.len() - count);
+                if !matches!(data_type_node, token::Token::ADD | token::Token::MUL) {
+                    let mut branches = branches.into_iter();
+                    built.push(Box::new(Node {
+                        data_type: data_type_node,
+                        left: branches.next(),
+                        right: branches.next(),
+                    }));
+                    continue;
+                }
+                let mut operands: Vec<Box<Node<N>>> = branches;
+                operands.sort_by(|a, b| order::cmp_node(a, b));
+                let mut operands = operands.into_iter();
+                let mut node: Box<Node<N>> = operands.next().unwrap();
+                for a in operands {
+                    node = Box::new(Node {
+                        data_type: data_type_node.clone(),
+                        left: Some(node),
+                        right: Some(a),
+                    });
+                }
+                built.push(node);
             }
         }
+    }
+    return Ok(built.pop().unwrap())
+}
 
-        // if NUM is valid for both first and second data_type
-        if first_num_bool == true && second_num_bool == true {
-            // if first NUM is less than or equal to second num
-            if first_num.unwrap() <= second_num.unwrap() {
-                left_branch_processed = first_branch_raw;
-                right_branch_processed = second_branch_raw;
-            // else, second NUM is less than first num
-            } else {
-                left_branch_processed = second_branch_raw;
-                right_branch_processed = first_branch_raw;
+/// Simplify the binary tree with the patterns in simplify_node, then flatten it into an expression to fold NUM values and collect like terms
+/// across the whole sum or product.
+pub fn simplify<N: Numeric>(node: &Node<N>) -> Box<Node<N>> {
+    return simplify_with(node, &assume::Assumptions::new())
+}
+
+/// Simplify the binary tree, also applying the rewrites that only hold under `assumptions` (see Expr::simplify_with.)
+pub fn simplify_with<N: Numeric>(node: &Node<N>, assumptions: &assume::Assumptions) -> Box<Node<N>> {
+    let simplified: Box<Node<N>> = simplify_node(Some(Box::new(node.clone())), assumptions).unwrap();
+    // A Node has no functions, and simplifying doesn't add any, so the simplified expression can always go back into one
+    return expr::Expr::from(&*simplified).simplify_with(assumptions).to_node().unwrap()
+}
+
+/// Build the binary tree of a token vector, without simplifying it. Comparisons can only be part of an Inequality.
+pub fn build<N: Numeric>(token_vector: Vec<token::Token<N>>) -> Result<Box<Node<N>>, ParseError> {
+    if token_vector.iter().any(token::Token::is_comparison) {
+        return Err(ParseError::UnexpectedComparison)
+    }
+    let (fixed_token_vector, group_locations): (Vec<token::Token<N>>, Vec<(i32, i32)>) = token::fix_groups(token_vector)?;
+    let table: SplitTable = split_table(&fixed_token_vector, &group_locations);
+    return node_creation(&fixed_token_vector, &table, 0, fixed_token_vector.len())
+}
+
+/// Build the terms of a chain of comparisons, without simplifying them.
+pub fn build_inequality<N: Numeric>(token_vector: Vec<token::Token<N>>) -> Result<Inequality<N>, ParseError> {
+    let (fixed_token_vector, group_locations): (Vec<token::Token<N>>, Vec<(i32, i32)>) = token::fix_groups(token_vector)?;
+    let table: SplitTable = split_table(&fixed_token_vector, &group_locations);
+
+    // Find the comparisons outside of any group, the terms are everything in between them.
+    let mut depth: i32 = 0;
+    let mut start: usize = 0;
+    let mut terms: Vec<Node<N>> = Vec::new();
+    let mut comparisons: Vec<token::Token<N>> = Vec::new();
+    for (i, x) in fixed_token_vector.iter().enumerate() {
+        match x {
+            token::Token::LGROUP => depth += 1,
+            token::Token::RGROUP => depth -= 1,
+            _ if x.is_comparison() => {
+                if depth != 0 {
+                    return Err(ParseError::ComparisonInGroup)
+                }
+                terms.push(*node_creation(&fixed_token_vector, &table, start, i)?);
+                comparisons.push(x.clone());
+                start = i + 1;
             }
-        // if NUM exists in only first
-        } else if first_num_bool == true {
-            left_branch_processed = first_branch_raw;
-            right_branch_processed = second_branch_raw;
-        // if NUM exists in only second
-        } else if second_num_bool == true {
-            left_branch_processed = second_branch_raw;
-            right_branch_processed = first_branch_raw;
-        // if VAR in both
-        } else if first_variable == true && second_variable == true {
-            left_branch_processed = first_branch_raw;
-            right_branch_processed = second_branch_raw;
-        // if VAR in only first
-        } else if first_variable == true {
-            left_branch_processed = first_branch_raw;
-            right_branch_processed = second_branch_raw;
-        // if VAR in only second
-        } else if second_variable == true {
-            left_branch_processed = second_branch_raw;
-            right_branch_processed = first_branch_raw;
-        // ABSTRACT in both
-        } else {
-            left_branch_processed = first_branch_raw;
-            right_branch_processed = second_branch_raw;
+            _ => {}
         }
-        // Declare the node
-        a = Node {
-            data_type: data_type_node,
-            left: left_branch_processed,
-            right: right_branch_processed,
-        };
+    }
+    terms.push(*node_creation(&fixed_token_vector, &table, start, fixed_token_vector.len())?);
 
+    // A chain such as 0 < x > 5 doesn't state an interval.
+    let ascending: bool = comparisons.iter().any(|a| matches!(a, token::Token::LT | token::Token::LE));
+    let descending: bool = comparisons.iter().any(|a| matches!(a, token::Token::GT | token::Token::GE));
+    if ascending && descending {
+        return Err(ParseError::MixedComparisons)
     }
-    // Return the node
-    return Some(Box::new(a))
-}
 
-pub fn process(token_vector: Vec<token::Token>) -> Box<Node> {
-    let unprocessed = token::fix_groups(token_vector);
-    let fixed_token_vector: Vec<token::Token> = unprocessed.0;
-    let group_locations: Vec<(i32, i32)> = unprocessed.1;
-    let binary_tree: Box<Node> = node_creation(split_locater(fixed_token_vector, group_locations)).unwrap();
-    println!("Before Simplifcation: {:#?}", binary_tree.clone());
-    let simplified = simplify_node(Some(binary_tree));
-    return simplified.unwrap();
-}
\ No newline at end of file
+    return Ok(Inequality {
+        terms: terms,
+        comparisons: comparisons,
+    })
+}
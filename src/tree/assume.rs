@@ -0,0 +1,147 @@
+// Assumptions about symbols. Some rewrites only hold for some values, eg. x / x is 1 unless x is 0, and sqrt(x^2) is x only when x isn't
+// negative, so the simplifier only applies them when the symbols involved are assumed to have values that make them hold. Without any
+// assumptions a symbol can be any value, including 0 and negative (or complex) ones.
+use super::expr::Expr;
+use super::numeric::Numeric;
+use super::store::{ExprId, ExprStore, Stored};
+use super::symbol::Symbol;
+use std::collections::HashMap;
+
+/// Something that can be assumed about a symbol. `Positive` also implies `Real` and `Nonzero`, and `Integer` implies `Real`.
+#[derive(Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Assumption {
+    Positive,
+    Real,
+    Integer,
+    Nonzero,
+}
+
+// Everything assumed about one symbol, with the implications already filled in.
+#[derive(Debug)]
+#[derive(Clone, Copy, Default)]
+struct Facts {
+    positive: bool,
+    real: bool,
+    integer: bool,
+    nonzero: bool,
+}
+
+/// The assumptions the simplifier may rely on, see `simplify_with`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+pub struct Assumptions {
+    facts: HashMap<Symbol, Facts>,
+}
+
+impl Assumptions {
+    /// No assumptions about any symbol.
+    pub fn new() -> Assumptions {
+        return Assumptions::default()
+    }
+
+    /// Assume something about a symbol, along with everything it implies.
+    pub fn assume<S: Into<Symbol>>(&mut self, sym: S, assumption: Assumption) -> &mut Assumptions {
+        let facts: &mut Facts = self.facts.entry(sym.into()).or_default();
+        match assumption {
+            Assumption::Positive => {
+                facts.positive = true;
+                facts.real = true;
+                facts.nonzero = true;
+            }
+            Assumption::Real => facts.real = true,
+            Assumption::Integer => {
+                facts.integer = true;
+                facts.real = true;
+            }
+            Assumption::Nonzero => facts.nonzero = true,
+        }
+        return self
+    }
+
+    fn facts(&self, sym: Symbol) -> Facts {
+        return self.facts.get(&sym).copied().unwrap_or_default()
+    }
+
+    /// Whether the expression is known to be greater than 0.
+    pub fn is_positive<N: Numeric>(&self, expr: &Expr<N>) -> bool {
+        let (store, id): (ExprStore<N>, ExprId) = interned(expr);
+        return self.is_positive_in(&store, id)
+    }
+
+    /// Whether the expression is known not to be 0.
+    pub fn is_nonzero<N: Numeric>(&self, expr: &Expr<N>) -> bool {
+        let (store, id): (ExprStore<N>, ExprId) = interned(expr);
+        return self.is_nonzero_in(&store, id)
+    }
+
+    /// Whether the expression is known to be a real number.
+    pub fn is_real<N: Numeric>(&self, expr: &Expr<N>) -> bool {
+        let (store, id): (ExprStore<N>, ExprId) = interned(expr);
+        return self.is_real_in(&store, id)
+    }
+
+    /// Whether the expression is known to be an integer.
+    pub fn is_integer<N: Numeric>(&self, expr: &Expr<N>) -> bool {
+        let (store, id): (ExprStore<N>, ExprId) = interned(expr);
+        return self.is_integer_in(&store, id)
+    }
+
+    // The same as is_positive, for an expression in a store, which is how the simplifier holds them.
+    pub(crate) fn is_positive_in<N: Numeric>(&self, store: &ExprStore<N>, id: ExprId) -> bool {
+        match store.get(id) {
+            Stored::Num(a) => return a.is_positive(),
+            Stored::Sym(a) => return self.facts(*a).positive,
+            Stored::Add(operands) | Stored::Mul(operands) => {
+                return !operands.is_empty() && operands.iter().all(|a| self.is_positive_in(store, *a))
+            }
+            Stored::Pow(base, exponent) => return self.is_positive_in(store, *base) && self.is_real_in(store, *exponent),
+            Stored::Func(_, _) => return false,
+        }
+    }
+
+    pub(crate) fn is_nonzero_in<N: Numeric>(&self, store: &ExprStore<N>, id: ExprId) -> bool {
+        match store.get(id) {
+            Stored::Num(a) => return !a.is_zero(),
+            Stored::Sym(a) => return self.facts(*a).nonzero,
+            Stored::Add(_) => return self.is_positive_in(store, id),
+            Stored::Mul(factors) => return factors.iter().all(|a| self.is_nonzero_in(store, *a)),
+            // A power of something other than 0 is never 0
+            Stored::Pow(base, _) => return self.is_nonzero_in(store, *base),
+            Stored::Func(_, _) => return false,
+        }
+    }
+
+    pub(crate) fn is_real_in<N: Numeric>(&self, store: &ExprStore<N>, id: ExprId) -> bool {
+        match store.get(id) {
+            Stored::Num(a) => return a.is_real(),
+            Stored::Sym(a) => return self.facts(*a).real,
+            Stored::Add(operands) | Stored::Mul(operands) => return operands.iter().all(|a| self.is_real_in(store, *a)),
+            Stored::Pow(base, exponent) => {
+                return (self.is_positive_in(store, *base) && self.is_real_in(store, *exponent))
+                    || (self.is_real_in(store, *base) && self.is_integer_in(store, *exponent))
+            }
+            Stored::Func(_, _) => return false,
+        }
+    }
+
+    pub(crate) fn is_integer_in<N: Numeric>(&self, store: &ExprStore<N>, id: ExprId) -> bool {
+        match store.get(id) {
+            Stored::Num(a) => return a.is_integer(),
+            Stored::Sym(a) => return self.facts(*a).integer,
+            Stored::Add(operands) | Stored::Mul(operands) => return operands.iter().all(|a| self.is_integer_in(store, *a)),
+            Stored::Pow(base, exponent) => {
+                return self.is_integer_in(store, *base) && self.is_integer_in(store, *exponent) && self.is_positive_in(store, *exponent)
+            }
+            Stored::Func(_, _) => return false,
+        }
+    }
+}
+
+// An expression interned in a store of its own, to ask about it the same way as the simplifier does.
+fn interned<N: Numeric>(expr: &Expr<N>) -> (ExprStore<N>, ExprId) {
+    let mut store: ExprStore<N> = ExprStore::new();
+    let id: ExprId = store.insert(expr);
+    return (store, id)
+}
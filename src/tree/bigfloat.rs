@@ -0,0 +1,426 @@
+// Binary floating point numbers with a PRECISION bit mantissa, for simplifying in floating point without the rounding of an f64 showing
+// up in the answer. A BigFloat is mantissa * 2^exponent with an odd mantissa (or 0), so each value has a single representation, and
+// every operation rounds its result to the nearest value with PRECISION bits. Powers with a fractional exponent go through exp and ln,
+// which work in fixed point with GUARD extra bits so their own rounding stays below the last bit of the result.
+use super::integer::Integer;
+use super::numeric::Numeric;
+use super::rational::Rational;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
+use std::str::FromStr;
+
+/// The number of bits in the mantissa of a BigFloat, about 77 decimal digits.
+pub const PRECISION: u64 = 256;
+
+// The number of significant digits a BigFloat is printed with, a couple less than PRECISION holds so that its rounding doesn't show.
+const DIGITS: i64 = 75;
+
+// The extra bits exp and ln work with.
+const GUARD: u64 = 64;
+
+// The largest power of 2 exp works out, beyond which a power is left as it is.
+const MAX_EXPONENT: i64 = 1 << 40;
+
+// The smallest negative power of 10 a BigFloat is printed without scientific notation for, eg. 1e-1000 is written out.
+const MAX_PLAIN_EXPONENT: i64 = 1000;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigFloat {
+    mantissa: Integer,
+    exponent: i64,
+}
+
+impl BigFloat {
+    pub const ZERO: BigFloat = BigFloat { mantissa: Integer::ZERO, exponent: 0 };
+
+    /// The number mantissa * 2^exponent, rounded to PRECISION bits.
+    pub fn new(mantissa: Integer, exponent: i64) -> BigFloat {
+        let mut mantissa: Integer = mantissa;
+        let mut exponent: i64 = exponent;
+        let excess: u64 = mantissa.bits().saturating_sub(PRECISION);
+        if excess > 0 {
+            // Round half away from 0, using the highest bit that is dropped
+            let rounded: Integer = mantissa.shr(excess - 1);
+            let step: Integer = if rounded.is_negative() { -Integer::ONE } else { Integer::ONE };
+            mantissa = (rounded + step).shr(1);
+            exponent += excess as i64;
+        }
+        if mantissa.is_zero() {
+            return BigFloat::ZERO
+        }
+        let zeros: u64 = mantissa.trailing_zeros();
+        return BigFloat {
+            mantissa: mantissa.shr(zeros),
+            exponent: exponent + zeros as i64,
+        }
+    }
+
+    /// The exact value of the number as a fraction.
+    pub fn to_rational(&self) -> Rational {
+        if self.exponent >= 0 {
+            return Rational::from(self.mantissa.shl(self.exponent as u64))
+        }
+        return Rational::new(self.mantissa.clone(), Integer::ONE.shl(self.exponent.unsigned_abs()))
+    }
+
+    // The number as a fixed point Integer with `bits` bits after the point, rounded towards 0.
+    fn to_fixed(&self, bits: u64) -> Integer {
+        let shift: i64 = self.exponent + bits as i64;
+        if shift >= 0 {
+            return self.mantissa.shl(shift as u64)
+        }
+        return self.mantissa.shr(shift.unsigned_abs())
+    }
+
+    // The number raised to an integer power, by squaring.
+    fn powi(&self, exponent: u64) -> BigFloat {
+        let mut base: BigFloat = self.clone();
+        let mut exponent: u64 = exponent;
+        let mut result: BigFloat = BigFloat::from_integer(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        return result
+    }
+}
+
+// ln((1 + z) / (1 - z)), which is 2 * atanh(z), for a fixed point z between -1 and 1.
+fn ln_ratio(z: &Integer, bits: u64) -> Integer {
+    let square: Integer = (z * z).shr(bits);
+    let mut power: Integer = z.clone();
+    let mut sum: Integer = Integer::ZERO;
+    let mut k: i64 = 1;
+    while !power.is_zero() {
+        sum = sum + &power / &Integer::from(k);
+        power = (&power * &square).shr(bits);
+        k += 2;
+    }
+    return sum.shl(1)
+}
+
+// ln 2 in fixed point, which is ln((1 + 1/3) / (1 - 1/3)).
+fn ln2(bits: u64) -> Integer {
+    return ln_ratio(&(Integer::ONE.shl(bits) / Integer::from(3)), bits)
+}
+
+// The natural log of a positive number in fixed point. The number is split into f * 2^k with f between 1/2 and 1, and ln f is
+// ln((1 + z) / (1 - z)) for z = (f - 1) / (f + 1).
+fn ln(x: &BigFloat, bits: u64) -> Integer {
+    let length: u64 = x.mantissa.bits();
+    let one: Integer = Integer::ONE.shl(bits);
+    let f: Integer = BigFloat::new(x.mantissa.clone(), -(length as i64)).to_fixed(bits);
+    let z: Integer = (&f - &one).shl(bits) / (&f + &one);
+    return ln_ratio(&z, bits) + ln2(bits) * Integer::from(length as i64 + x.exponent)
+}
+
+// e to the power of a fixed point number, if it isn't too large to store. It is split into r + n ln 2 with r below ln 2, and e^r is
+// summed from its series after dividing r by 2^8 (which is then squared back 8 times), so that the series is short.
+fn exp(y: &Integer, bits: u64) -> Option<BigFloat> {
+    let ln2: Integer = ln2(bits);
+    let n: i64 = (y / &ln2).to_i64().filter(|a| a.abs() < MAX_EXPONENT)?;
+    let r: Integer = (y - &(&ln2 * &Integer::from(n))).shr(8);
+
+    let one: Integer = Integer::ONE.shl(bits);
+    let mut term: Integer = one.clone();
+    let mut sum: Integer = one;
+    let mut k: i64 = 1;
+    loop {
+        term = (&term * &r).shr(bits) / Integer::from(k);
+        if term.is_zero() {
+            break;
+        }
+        sum = sum + &term;
+        k += 1;
+    }
+    for _ in 0..8 {
+        sum = (&sum * &sum).shr(bits);
+    }
+    return Some(BigFloat::new(sum, n - bits as i64))
+}
+
+impl Numeric for BigFloat {
+    fn from_integer(value: i64) -> BigFloat {
+        return BigFloat::new(Integer::from(value), 0)
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.mantissa.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        return self.mantissa.is_negative()
+    }
+
+    // The mantissa is odd, so only a number without bits after the point is an integer
+    fn is_integer(&self) -> bool {
+        return self.exponent >= 0
+    }
+
+    fn pow(&self, exponent: &BigFloat) -> Option<BigFloat> {
+        if self.is_zero() {
+            if exponent.is_negative() {
+                return None
+            } else if exponent.is_zero() {
+                return Some(BigFloat::from_integer(1))
+            }
+            return Some(BigFloat::ZERO)
+        }
+        // A power with an exponent of 2^40 or more isn't worked out, and neither is an integer power that would be that many bits long
+        if exponent.exponent + exponent.mantissa.bits() as i64 > 40 {
+            return None
+        }
+        if exponent.is_integer() {
+            let length: u64 = (self.exponent + self.mantissa.bits() as i64).unsigned_abs() + 1;
+            let power: u64 = exponent.to_fixed(0).abs().to_i64()? as u64;
+            if length.saturating_mul(power) >= MAX_EXPONENT as u64 {
+                return None
+            }
+            let power: BigFloat = self.powi(power);
+            if exponent.is_negative() {
+                return Some(BigFloat::from_integer(1) / power)
+            }
+            return Some(power)
+        }
+        // A power that is exact as a fraction is kept exact, eg. 4^0.5 is 2 rather than a float next to it
+        if self.exponent.unsigned_abs() <= PRECISION && exponent.exponent.unsigned_abs() <= PRECISION {
+            if let Some(a) = self.to_rational().pow(&exponent.to_rational()) {
+                return Some(BigFloat::from(&a))
+            }
+        }
+        if self.is_negative() {
+            return None
+        }
+        let bits: u64 = PRECISION + GUARD;
+        return exp(&(exponent.to_fixed(bits) * ln(self, bits)).shr(bits), bits)
+    }
+
+    fn cmp_num(&self, other: &BigFloat) -> Ordering {
+        return self.cmp(other)
+    }
+
+    fn hash_num<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+
+    fn to_f64(&self) -> f64 {
+        // Keep the top 64 bits of the mantissa, which is more than an f64 holds
+        let shift: u64 = self.mantissa.bits().saturating_sub(64);
+        let exponent: i64 = (self.exponent + shift as i64).clamp(i32::MIN as i64, i32::MAX as i64);
+        return self.mantissa.shr(shift).to_f64() * 2f64.powi(exponent as i32)
+    }
+}
+
+impl From<&Rational> for BigFloat {
+    fn from(value: &Rational) -> BigFloat {
+        return BigFloat::new(value.numerator().clone(), 0) / BigFloat::new(value.denominator().clone(), 0)
+    }
+}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &BigFloat) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigFloat {
+    // Compare the signs, then the positions of the highest bits, and only then the mantissas, lined up. This never has to shift a
+    // mantissa by more than its own length, however far apart the exponents are.
+    fn cmp(&self, other: &BigFloat) -> Ordering {
+        let sign = |a: &BigFloat| -> i8 {
+            return if a.is_negative() { -1 } else if a.is_zero() { 0 } else { 1 }
+        };
+        if sign(self) != sign(other) || self.is_zero() {
+            return sign(self).cmp(&sign(other))
+        }
+        let top: i64 = self.exponent + self.mantissa.bits() as i64;
+        let other_top: i64 = other.exponent + other.mantissa.bits() as i64;
+        let magnitude: Ordering = if top != other_top {
+            top.cmp(&other_top)
+        } else {
+            let exponent: i64 = self.exponent.min(other.exponent);
+            let left: Integer = self.mantissa.abs().shl((self.exponent - exponent) as u64);
+            left.cmp(&other.mantissa.abs().shl((other.exponent - exponent) as u64))
+        };
+        if self.is_negative() {
+            return magnitude.reverse()
+        }
+        return magnitude
+    }
+}
+
+impl Add for &BigFloat {
+    type Output = BigFloat;
+
+    fn add(self, other: &BigFloat) -> BigFloat {
+        if self.is_zero() {
+            return other.clone()
+        } else if other.is_zero() {
+            return self.clone()
+        }
+        // A number entirely below the last bit of the other one can't change it
+        let top: i64 = self.exponent + self.mantissa.bits() as i64;
+        let other_top: i64 = other.exponent + other.mantissa.bits() as i64;
+        if top - other_top > PRECISION as i64 + 2 {
+            return self.clone()
+        } else if other_top - top > PRECISION as i64 + 2 {
+            return other.clone()
+        }
+        let exponent: i64 = self.exponent.min(other.exponent);
+        let sum: Integer = self.mantissa.shl((self.exponent - exponent) as u64) + other.mantissa.shl((other.exponent - exponent) as u64);
+        return BigFloat::new(sum, exponent)
+    }
+}
+
+impl Sub for &BigFloat {
+    type Output = BigFloat;
+
+    fn sub(self, other: &BigFloat) -> BigFloat {
+        return self + &-other
+    }
+}
+
+impl Mul for &BigFloat {
+    type Output = BigFloat;
+
+    fn mul(self, other: &BigFloat) -> BigFloat {
+        return BigFloat::new(&self.mantissa * &other.mantissa, self.exponent + other.exponent)
+    }
+}
+
+impl Div for &BigFloat {
+    type Output = BigFloat;
+
+    // Shift the dividend so the quotient has a couple more bits than PRECISION before it is rounded. There is no infinity to give for a
+    // division by 0, so like an integer division it crashes, but the simplifier never divides by a 0 NUM (see Numeric::pow.)
+    fn div(self, other: &BigFloat) -> BigFloat {
+        if other.is_zero() {
+            panic!("Can't divide by 0!");
+        }
+        let shift: u64 = (PRECISION + 2 + other.mantissa.bits()).saturating_sub(self.mantissa.bits());
+        return BigFloat::new(self.mantissa.shl(shift) / &other.mantissa, self.exponent - other.exponent - shift as i64)
+    }
+}
+
+impl Neg for &BigFloat {
+    type Output = BigFloat;
+
+    fn neg(self) -> BigFloat {
+        return BigFloat {
+            mantissa: -&self.mantissa,
+            exponent: self.exponent,
+        }
+    }
+}
+
+impl Neg for BigFloat {
+    type Output = BigFloat;
+
+    fn neg(self) -> BigFloat {
+        return -&self
+    }
+}
+
+// The operators on owned numbers, which borrow them for the operators above.
+macro_rules! owned_operators {
+    ($($operator:ident $method:ident),*) => {
+        $(
+            impl $operator for BigFloat {
+                type Output = BigFloat;
+
+                fn $method(self, other: BigFloat) -> BigFloat {
+                    return (&self).$method(&other)
+                }
+            }
+        )*
+    };
+}
+
+owned_operators!(Add add, Sub sub, Mul mul, Div div);
+
+impl AddAssign for BigFloat {
+    fn add_assign(&mut self, other: BigFloat) {
+        *self = &*self + &other;
+    }
+}
+
+impl MulAssign for BigFloat {
+    fn mul_assign(&mut self, other: BigFloat) {
+        *self = &*self * &other;
+    }
+}
+
+/// Reads anything a Rational reads, rounded to the nearest BigFloat, eg. `0.1` or `1/3`.
+impl FromStr for BigFloat {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<BigFloat, String> {
+        return input.parse::<Rational>().map(|a| BigFloat::from(&a))
+    }
+}
+
+// Printed as a decimal rounded to DIGITS significant digits, without trailing zeros, eg. 0.333333333333333333333333333333333333333333333333
+//333333333333333333333333333. A number with more than DIGITS digits before the point is printed in scientific notation instead, so the
+// digits past the precision aren't written out as zeros, eg. 2^1000 is 1.0715086071862673209...e301, and so is a number too small to
+// write out, eg. 1.5e-2000.
+impl fmt::Display for BigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0")
+        }
+        // The power of 10 of the first digit, give or take 1, and so the number of decimal places to round to
+        let magnitude: BigFloat = self.abs();
+        let first: i64 = ((magnitude.mantissa.bits() as i64 + self.exponent - 1) as f64 * std::f64::consts::LOG10_2).floor() as i64;
+        let places: i64 = DIGITS - 1 - first;
+        let plain: bool = (-MAX_PLAIN_EXPONENT..DIGITS).contains(&first);
+
+        // Scale the number to an integer with DIGITS digits, exactly if it is written out, and otherwise in BigFloat (which is precise
+        // enough for its first DIGITS digits)
+        let scaled: Rational = if plain {
+            &magnitude.to_rational() * &Rational::from(Integer::from(10).pow(places.unsigned_abs() as u32)).powi(places.signum() as i32)
+        } else if places >= 0 {
+            (&magnitude * &BigFloat::from_integer(10).powi(places as u64)).to_rational()
+        } else {
+            (&magnitude / &BigFloat::from_integer(10).powi(places.unsigned_abs())).to_rational()
+        };
+        let numerator: &Integer = scaled.numerator();
+        let denominator: &Integer = scaled.denominator();
+        let digits: String = ((numerator.shl(1) + denominator) / denominator.shl(1)).to_string();
+
+        let mut decimal: String = if self.is_negative() { "-".to_string() } else { String::new() };
+        if !plain {
+            let (whole, fraction) = digits.split_at(1);
+            decimal.push_str(whole);
+            let fraction: &str = fraction.trim_end_matches('0');
+            if !fraction.is_empty() {
+                decimal.push('.');
+                decimal.push_str(fraction);
+            }
+            decimal.push_str(&format!("e{}", digits.len() as i64 - 1 - places));
+        } else {
+            let places: usize = places.max(0) as usize;
+            let digits: String = format!("{}{}", "0".repeat((places + 1).saturating_sub(digits.len())), digits);
+            let (whole, fraction) = digits.split_at(digits.len() - places);
+            decimal.push_str(whole);
+            let fraction: &str = fraction.trim_end_matches('0');
+            if !fraction.is_empty() {
+                decimal.push('.');
+                decimal.push_str(fraction);
+            }
+        }
+        return f.write_str(&decimal)
+    }
+}
+
+impl fmt::Debug for BigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self)
+    }
+}
@@ -0,0 +1,136 @@
+// Building expressions in Rust. sym and num make the values, and the usual operators join them, so 3x^2 - y can be written as
+// 3 * sym("x").pow(2) - sym("y"). Like the tokenizer, subtraction is addition of -1 times the right side, and division is multiplication
+// by the right side to the power of -1. Sums and products are kept flat as they are built, but nothing is simplified.
+use super::expr::Expr;
+use super::rational::Rational;
+use super::symbol::Symbol;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A symbol.
+pub fn sym(name: &str) -> Expr {
+    return Expr::Sym(Symbol::new(name))
+}
+
+/// A NUM value. A float has to be converted with `Rational::try_from` first, which reads it as the decimal it prints as, eg. 0.1 is 1/10.
+pub fn num<N: Into<Rational>>(value: N) -> Expr {
+    return Expr::Num(value.into())
+}
+
+impl Expr {
+    /// Raise the expression to a power.
+    pub fn pow<E: Into<Expr>>(&self, exponent: E) -> Expr {
+        return Expr::Pow(Box::new(self.clone()), Box::new(exponent.into()))
+    }
+}
+
+impl From<Symbol> for Expr {
+    fn from(sym: Symbol) -> Expr {
+        return Expr::Sym(sym)
+    }
+}
+
+impl From<&Expr> for Expr {
+    fn from(expr: &Expr) -> Expr {
+        return expr.clone()
+    }
+}
+
+impl From<i32> for Expr {
+    fn from(value: i32) -> Expr {
+        return num(value)
+    }
+}
+
+impl From<Rational> for Expr {
+    fn from(value: Rational) -> Expr {
+        return num(value)
+    }
+}
+
+// Join two operands into a sum or product, extending either side if it is already one.
+fn join(left: Expr, right: Expr, product: bool) -> Expr {
+    let mut operands: Vec<Expr> = Vec::new();
+    for a in [left, right] {
+        match a {
+            Expr::Mul(inner) if product => operands.extend(inner),
+            Expr::Add(inner) if !product => operands.extend(inner),
+            a => operands.push(a),
+        }
+    }
+    if product {
+        return Expr::Mul(operands)
+    }
+    return Expr::Add(operands)
+}
+
+impl Neg for Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Expr {
+        return join(num(-1), self, true)
+    }
+}
+
+impl Neg for &Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Expr {
+        return -self.clone()
+    }
+}
+
+fn add(left: Expr, right: Expr) -> Expr {
+    return join(left, right, false)
+}
+
+fn sub(left: Expr, right: Expr) -> Expr {
+    return join(left, -right, false)
+}
+
+fn mul(left: Expr, right: Expr) -> Expr {
+    return join(left, right, true)
+}
+
+fn div(left: Expr, right: Expr) -> Expr {
+    return join(left, right.pow(-1), true)
+}
+
+// Implement each operator for an Expr (or a reference to one) on the left and anything that becomes an Expr on the right, and for a
+// number on the left, eg. 3 * x.
+macro_rules! operators {
+    ($($operator:ident $method:ident),*) => {$(
+        impl<T: Into<Expr>> $operator<T> for Expr {
+            type Output = Expr;
+
+            fn $method(self, right: T) -> Expr {
+                return $method(self, right.into())
+            }
+        }
+
+        impl<T: Into<Expr>> $operator<T> for &Expr {
+            type Output = Expr;
+
+            fn $method(self, right: T) -> Expr {
+                return $method(self.clone(), right.into())
+            }
+        }
+
+        impl $operator<Expr> for i32 {
+            type Output = Expr;
+
+            fn $method(self, right: Expr) -> Expr {
+                return $method(Expr::from(self), right)
+            }
+        }
+
+        impl $operator<&Expr> for i32 {
+            type Output = Expr;
+
+            fn $method(self, right: &Expr) -> Expr {
+                return $method(Expr::from(self), right.clone())
+            }
+        }
+    )*};
+}
+
+operators!(Add add, Sub sub, Mul mul, Div div);
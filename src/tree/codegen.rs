@@ -0,0 +1,212 @@
+// Generating source code from a Node, so simplified formulas can be pasted into Rust, C or Python (NumPy) programs. The tree is read
+// through print::infix, so subtraction, negation and the parentheses come out the same as the plain printer's. Only an EXP differs
+// between the languages: Rust calls powi or powf on the base, C calls pow, and Python uses ** (which works on NumPy arrays too). Every
+// value is a double precision float, or a complex one when the node holds a complex NUM: the imaginary unit is I from complex.h in C
+// (with cpow for a power of a complex value), and a j suffix in Python. Rust has no complex numbers, so a complex NUM can't be generated in it.
+// Names are written as they are when they are identifiers in the language. A Rust keyword is written as a raw identifier, eg. r#fn, but
+// C and Python have no way to escape a keyword, so a name such as return or lambda can't be generated in them.
+use super::Node;
+use super::expr::Expr;
+use super::print::{infix, precedence, Infix, Operator, ATOM, PRODUCT};
+use super::symbol::Symbol;
+use super::numeric::Numeric;
+use super::token::Token;
+
+/// A language to generate source code in.
+#[derive(Debug)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    C,
+    Python,
+}
+
+impl<N: Numeric> Node<N> {
+    /// The node as an expression in `language`, eg. `3.0 * x.powi(2) - 1.0 / x` in Rust, or `3.0 * x ** 2.0 - 1.0 / x` in Python. A
+    /// complex NUM is written with `I` in C and `j` in Python, eg. `3.0 + 2.0 * I` and `3.0 + 2.0j`.
+    ///
+    /// # Panics
+    /// When generating Rust for a node that holds a complex NUM, or when the name of a VAR isn't an identifier in `language` (eg. `x'`,
+    /// or a keyword such as `lambda` in Python). Rust keywords are written as raw identifiers instead, eg. `r#fn`.
+    pub fn to_code(&self, language: Language) -> String {
+        if language == Language::Rust && is_complex(self) {
+            panic!("Rust has no complex numbers, so {} can't be generated in it!", self);
+        }
+        let mut code: String = String::new();
+        generate(self, language, false, &mut code);
+        return code
+    }
+
+    /// A function called `name` returning the node, which takes each VAR of the node (sorted by name) as a parameter. The C function
+    /// needs `math.h` for `pow`, and if the node holds a complex NUM, returns a `double complex` from `complex.h`.
+    ///
+    /// # Panics
+    /// The same as `to_code`, and when `name` isn't an identifier in `language`.
+    pub fn to_function(&self, name: &str, language: Language) -> String {
+        let name: String = identifier(name, language);
+        let variables: Vec<String> = Expr::from(self).free_symbols().into_iter().map(|a: Symbol| identifier(a.as_str(), language)).collect();
+        let body: String = self.to_code(language);
+        match language {
+            Language::Rust => {
+                let parameters: Vec<String> = variables.iter().map(|a| format!("{}: f64", a)).collect();
+                return format!("pub fn {}({}) -> f64 {{\n    {}\n}}\n", name, parameters.join(", "), body)
+            }
+            Language::C => {
+                let parameters: Vec<String> = variables.iter().map(|a| format!("double {}", a)).collect();
+                let result: &str = if is_complex(self) { "double complex" } else { "double" };
+                return format!("{} {}({}) {{\n    return {};\n}}\n", result, name, parameters.join(", "), body)
+            }
+            Language::Python => {
+                return format!("def {}({}):\n    return {}\n", name, variables.join(", "), body)
+            }
+        }
+    }
+}
+
+const RUST_KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do", "dyn", "else", "enum", "extern",
+    "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+// I is the imaginary unit from complex.h, so it can't be a name either.
+const C_KEYWORDS: [&str; 45] = [
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else", "enum", "extern", "float", "for", "goto",
+    "if", "inline", "int", "long", "register", "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch",
+    "typedef", "union", "unsigned", "void", "volatile", "while", "_Alignas", "_Alignof", "_Atomic", "_Bool", "_Complex", "_Generic",
+    "_Imaginary", "_Noreturn", "_Static_assert", "_Thread_local", "I",
+];
+
+const PYTHON_KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else",
+    "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise",
+    "return", "try", "while", "with", "yield",
+];
+
+// A name as an identifier in the language. It has to start with a letter or an underscore and go on with letters, digits and
+// underscores, which are only ASCII in C. A Rust keyword is escaped as a raw identifier, except for the few that can't be raw.
+fn identifier(name: &str, language: Language) -> String {
+    let valid: bool = match language {
+        Language::C => {
+            name.starts_with(|a: char| a.is_ascii_alphabetic() || a == '_') && name.chars().all(|a| a.is_ascii_alphanumeric() || a == '_')
+        }
+        _ => name.starts_with(|a: char| a.is_alphabetic() || a == '_') && name.chars().all(|a| a.is_alphanumeric() || a == '_'),
+    };
+    let keywords: &[&str] = match language {
+        Language::Rust => &RUST_KEYWORDS,
+        Language::C => &C_KEYWORDS,
+        Language::Python => &PYTHON_KEYWORDS,
+    };
+    if language == Language::Rust && ["_", "crate", "self", "Self", "super"].contains(&name) {
+        panic!("{} can't be a raw identifier in Rust, so it can't be generated!", name);
+    } else if valid && !keywords.contains(&name) {
+        return name.to_string()
+    } else if valid && language == Language::Rust {
+        return format!("r#{}", name)
+    }
+    panic!("{} isn't an identifier in {:?}, so it can't be generated!", name, language);
+}
+
+// A NUM (an integer or a float, see print::infix) as a floating point literal, with a decimal point so C and Python don't read it as an
+// integer. A number in scientific notation (eg. a large BigFloat, 1e400) is already read as a float.
+fn literal<N: Numeric>(num: &N) -> String {
+    let text: String = num.to_string();
+    if text.contains('.') || text.contains('e') {
+        return text
+    }
+    return format!("{}.0", text)
+}
+
+// Whether the node holds a complex NUM anywhere.
+fn is_complex<N: Numeric>(node: &Node<N>) -> bool {
+    match &node.data_type {
+        Token::NUM(a) => return !a.is_real(),
+        _ => return node.left.as_deref().is_some_and(is_complex) || node.right.as_deref().is_some_and(is_complex),
+    }
+}
+
+// A complex NUM, read the same way as print::infix does, with the imaginary unit of the language in place of i.
+fn complex<N: Numeric>(re: N, im: N, language: Language, code: &mut String) {
+    let imaginary = |im: &N| -> String {
+        match language {
+            Language::Python => return format!("{}j", literal(im)),
+            _ if *im == N::from_integer(1) => return "I".to_string(),
+            _ => return format!("{} * I", literal(im)),
+        }
+    };
+    if re.is_zero() {
+        if im == N::from_integer(-1) {
+            code.push('-');
+            code.push_str(&imaginary(&im.abs()));
+        } else {
+            code.push_str(&imaginary(&im));
+        }
+        return
+    }
+    code.push_str(&literal(&re));
+    code.push_str(if im.is_negative() { " - " } else { " + " });
+    code.push_str(&imaginary(&im.abs()));
+}
+
+// With typed, the NUM values are written as f64 literals in Rust, since a method can't be called on a float literal of unknown type.
+fn generate<N: Numeric>(node: &Node<N>, language: Language, typed: bool, code: &mut String) {
+    if let Token::NUM(a) = &node.data_type {
+        if let Some((re, im)) = a.complex_parts() {
+            complex(re, im, language, code);
+            return
+        }
+    }
+    match infix(node) {
+        Infix::Num(a) if typed && language == Language::Rust => code.push_str(&format!("{}_f64", literal(a))),
+        Infix::Num(a) => code.push_str(&literal(a)),
+        Infix::Var(a) => code.push_str(&identifier(a.as_str(), language)),
+        Infix::Neg(a) => {
+            code.push('-');
+            operand(&a, PRODUCT, language, typed, code);
+        }
+        Infix::Binary(Operator::Exp, left, right) => match language {
+            Language::Rust => {
+                operand(&left, ATOM, language, true, code);
+                match right.data_type {
+                    Token::NUM(ref a) if a.is_integer() && a.abs() <= N::from_integer(i32::MAX as i64) => code.push_str(&format!(".powi({})", a)),
+                    _ => {
+                        code.push_str(".powf(");
+                        generate(&right, language, false, code);
+                        code.push(')');
+                    }
+                }
+            }
+            Language::C => {
+                code.push_str(if is_complex(&left) || is_complex(&right) { "cpow(" } else { "pow(" });
+                generate(&left, language, typed, code);
+                code.push_str(", ");
+                generate(&right, language, typed, code);
+                code.push(')');
+            }
+            Language::Python => {
+                let (left_bound, right_bound): (u8, u8) = Operator::Exp.bounds();
+                operand(&left, left_bound, language, typed, code);
+                code.push_str(" ** ");
+                operand(&right, right_bound, language, typed, code);
+            }
+        },
+        Infix::Binary(operator, left, right) => {
+            let (left_bound, right_bound): (u8, u8) = operator.bounds();
+            operand(&left, left_bound, language, typed, code);
+            code.push_str(&format!(" {} ", operator.symbol()));
+            operand(&right, right_bound, language, typed, code);
+        }
+    }
+}
+
+// Generate a node, wrapped in parentheses if it binds looser than bound.
+fn operand<N: Numeric>(node: &Node<N>, bound: u8, language: Language, typed: bool, code: &mut String) {
+    if precedence(node) < bound {
+        code.push('(');
+        generate(node, language, typed, code);
+        code.push(')');
+    } else {
+        generate(node, language, typed, code);
+    }
+}
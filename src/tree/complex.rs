@@ -0,0 +1,392 @@
+// Complex numbers a + bi over a real Numeric type: Complex<Rational> are the exact Gaussian rationals, and Complex<f32>, Complex<f64> and
+// Complex<BigFloat> are floating point complex numbers. Read with parse_as, `i` is the imaginary unit, so i ^ 2 folds to -1 and (-4) ^ 0.5 to 2 * i. A power
+// is worked out exactly whenever it can be (integer powers, and square roots with rational parts), and otherwise through the polar form
+// for f32 and f64, or left as it is otherwise.
+use super::bigfloat::BigFloat;
+use super::numeric::Numeric;
+use super::rational::Rational;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hasher;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
+use std::str::FromStr;
+
+// The largest integer power of a complex number that isn't real which is multiplied out.
+const MAX_POWER: i64 = 1 << 16;
+
+/// A real number type that complex numbers can be built from.
+pub trait Real: Numeric {
+    /// A power that can't be worked out exactly, if the type can approximate it. Only floats can, so this is None by default.
+    fn polar_pow(_base: &Complex<Self>, _exponent: &Complex<Self>) -> Option<Complex<Self>> {
+        return None
+    }
+}
+
+impl Real for Rational {}
+
+// BigFloat has no trigonometric functions to work out a polar power with, so only the exact powers and square roots are worked out.
+impl Real for BigFloat {}
+
+// z^w is e^(w ln z), where ln z is ln |z| + i arg z for the principal value.
+macro_rules! float_real {
+    ($($float:ident),*) => {
+        $(
+            impl Real for $float {
+                fn polar_pow(base: &Complex<$float>, exponent: &Complex<$float>) -> Option<Complex<$float>> {
+                    let length: $float = base.re.hypot(base.im).ln();
+                    let angle: $float = base.im.atan2(base.re);
+                    let re: $float = exponent.re * length - exponent.im * angle;
+                    let im: $float = exponent.re * angle + exponent.im * length;
+                    let power: Complex<$float> = Complex::new(re.exp() * im.cos(), re.exp() * im.sin());
+                    if power.re.is_nan() || power.im.is_nan() {
+                        return None
+                    }
+                    return Some(power)
+                }
+            }
+        )*
+    };
+}
+
+float_real!(f32, f64);
+
+#[derive(Clone, PartialEq)]
+pub struct Complex<N> {
+    re: N,
+    im: N,
+}
+
+impl<N: Numeric> Complex<N> {
+    pub fn new(re: N, im: N) -> Complex<N> {
+        return Complex {
+            re: re,
+            im: im,
+        }
+    }
+
+    /// The imaginary unit.
+    pub fn i() -> Complex<N> {
+        return Complex::new(N::from_integer(0), N::from_integer(1))
+    }
+
+    /// The real part.
+    pub fn re(&self) -> &N {
+        return &self.re
+    }
+
+    /// The imaginary part.
+    pub fn im(&self) -> &N {
+        return &self.im
+    }
+
+    fn real(re: N) -> Complex<N> {
+        return Complex::new(re, N::from_integer(0))
+    }
+
+    // The number raised to an integer power, by squaring.
+    fn powi(&self, exponent: i64) -> Complex<N> {
+        let mut base: Complex<N> = self.clone();
+        let mut power: u64 = exponent.unsigned_abs();
+        let mut result: Complex<N> = Complex::real(N::from_integer(1));
+        while power > 0 {
+            if power & 1 == 1 {
+                result *= base.clone();
+            }
+            power >>= 1;
+            if power > 0 {
+                base = base.clone() * base;
+            }
+        }
+        if exponent < 0 {
+            return Complex::real(N::from_integer(1)) / result
+        }
+        return result
+    }
+
+    // The principal square root, (|z| + a) / 2 and (|z| - a) / 2 being the squares of its real and imaginary parts. None if those
+    // can't be stored, eg. a root of 2 as a Rational.
+    fn sqrt(&self) -> Option<Complex<N>> {
+        let half: N = N::from_integer(1) / N::from_integer(2);
+        let root = |a: N| -> Option<N> {
+            // Rounding can take a float just below 0
+            if a.is_negative() {
+                return Some(N::from_integer(0))
+            }
+            return a.pow(&half)
+        };
+        let length: N = root(self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone())?;
+        let re: N = root((length.clone() + self.re.clone()) * half.clone())?;
+        let im: N = root((length - self.re.clone()) * half.clone())?;
+        if self.im.is_negative() {
+            return Some(Complex::new(re, -im))
+        }
+        return Some(Complex::new(re, im))
+    }
+
+    // The power if it can be worked out exactly: a real power of a real number that N can work out, an integer power, or a power of a
+    // square root.
+    fn exact_pow(&self, exponent: &Complex<N>) -> Option<Complex<N>> {
+        if !exponent.im.is_zero() {
+            return None
+        }
+        let power: &N = &exponent.re;
+        if self.im.is_zero() && (power.is_integer() || !self.re.is_negative()) {
+            return self.re.pow(power).map(Complex::real)
+        }
+        let doubled: N = power.clone() + power.clone();
+        if !doubled.is_integer() || doubled.abs() > N::from_integer(MAX_POWER) {
+            return None
+        }
+        if power.is_integer() {
+            return Some(self.powi(power.to_f64() as i64))
+        }
+        return Some(self.sqrt()?.powi(doubled.to_f64() as i64))
+    }
+}
+
+impl<N: Real> Numeric for Complex<N> {
+    fn from_integer(value: i64) -> Complex<N> {
+        return Complex::real(N::from_integer(value))
+    }
+
+    fn is_zero(&self) -> bool {
+        return self.re.is_zero() && self.im.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        return self.im.is_zero() && self.re.is_negative()
+    }
+
+    fn is_positive(&self) -> bool {
+        return self.im.is_zero() && self.re.is_positive()
+    }
+
+    fn is_integer(&self) -> bool {
+        return self.im.is_zero() && self.re.is_integer()
+    }
+
+    fn is_real(&self) -> bool {
+        return self.im.is_zero()
+    }
+
+    fn pow(&self, exponent: &Complex<N>) -> Option<Complex<N>> {
+        // 0 to a power with a real part that isn't positive divides by 0
+        if self.is_zero() && !exponent.is_zero() && !exponent.re.is_positive() {
+            return None
+        }
+        return self.exact_pow(exponent).or_else(|| N::polar_pow(self, exponent))
+    }
+
+    fn split_root(&self, exponent: &Complex<N>) -> Option<(Complex<N>, Complex<N>, Complex<N>)> {
+        if !self.im.is_zero() || !exponent.im.is_zero() {
+            return None
+        }
+        let (coefficient, base, power): (N, N, N) = self.re.split_root(&exponent.re)?;
+        return Some((Complex::real(coefficient), Complex::real(base), Complex::real(power)))
+    }
+
+    // By the real part, and then the imaginary part
+    fn cmp_num(&self, other: &Complex<N>) -> Ordering {
+        return self.re.cmp_num(&other.re).then_with(|| self.im.cmp_num(&other.im))
+    }
+
+    fn hash_num<H: Hasher>(&self, state: &mut H) {
+        self.re.hash_num(state);
+        self.im.hash_num(state);
+    }
+
+    fn to_f64(&self) -> f64 {
+        if self.im.is_zero() {
+            return self.re.to_f64()
+        }
+        return f64::NAN
+    }
+
+    fn fraction(&self) -> Option<(Complex<N>, Complex<N>)> {
+        if !self.im.is_zero() {
+            return None
+        }
+        let (numerator, denominator) = self.re.fraction()?;
+        return Some((Complex::real(numerator), Complex::real(denominator)))
+    }
+
+    fn complex_parts(&self) -> Option<(Complex<N>, Complex<N>)> {
+        if self.im.is_zero() {
+            return None
+        }
+        return Some((Complex::real(self.re.clone()), Complex::real(self.im.clone())))
+    }
+}
+
+/// Only real numbers are ordered, eg. 1 < 2, but 1 and i can't be compared.
+impl<N: Numeric> PartialOrd for Complex<N> {
+    fn partial_cmp(&self, other: &Complex<N>) -> Option<Ordering> {
+        if !self.im.is_zero() || !other.im.is_zero() {
+            if self == other {
+                return Some(Ordering::Equal)
+            }
+            return None
+        }
+        return self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<N: Numeric> Add for Complex<N> {
+    type Output = Complex<N>;
+
+    fn add(self, other: Complex<N>) -> Complex<N> {
+        return Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<N: Numeric> Sub for Complex<N> {
+    type Output = Complex<N>;
+
+    fn sub(self, other: Complex<N>) -> Complex<N> {
+        return Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<N: Numeric> Mul for Complex<N> {
+    type Output = Complex<N>;
+
+    fn mul(self, other: Complex<N>) -> Complex<N> {
+        let re: N = self.re.clone() * other.re.clone() - self.im.clone() * other.im.clone();
+        return Complex::new(re, self.re * other.im + self.im * other.re)
+    }
+}
+
+impl<N: Numeric> Div for Complex<N> {
+    type Output = Complex<N>;
+
+    // Multiply both sides by the conjugate of the divisor, which makes the divisor real.
+    fn div(self, other: Complex<N>) -> Complex<N> {
+        let norm: N = other.re.clone() * other.re.clone() + other.im.clone() * other.im.clone();
+        let re: N = self.re.clone() * other.re.clone() + self.im.clone() * other.im.clone();
+        let im: N = self.im * other.re - self.re * other.im;
+        return Complex::new(re / norm.clone(), im / norm)
+    }
+}
+
+impl<N: Numeric> Neg for Complex<N> {
+    type Output = Complex<N>;
+
+    fn neg(self) -> Complex<N> {
+        return Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<N: Numeric> AddAssign for Complex<N> {
+    fn add_assign(&mut self, other: Complex<N>) {
+        *self = self.clone() + other;
+    }
+}
+
+impl<N: Numeric> MulAssign for Complex<N> {
+    fn mul_assign(&mut self, other: Complex<N>) {
+        *self = self.clone() * other;
+    }
+}
+
+/// Reads a real number, an imaginary one such as `2i` or `i`, or both such as `3+2i` (without spaces, the way they are printed.)
+impl<N: Numeric> FromStr for Complex<N> {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Complex<N>, String> {
+        let invalid = || -> String {
+            return format!("{} isn't a number!", input)
+        };
+        let part = |text: &str| -> Result<N, String> {
+            return text.parse::<N>().map_err(|_| invalid())
+        };
+        let imaginary = match input.strip_suffix('i') {
+            Some(a) => a,
+            None => return Ok(Complex::real(part(input)?)),
+        };
+        // The sign between the two parts, skipping the sign of the real part and of an exponent such as 1e-5
+        let bytes: &[u8] = imaginary.as_bytes();
+        let split: Option<usize> = (1..bytes.len()).rev().find(|&a| (bytes[a] == b'+' || bytes[a] == b'-') && !matches!(bytes[a - 1], b'e' | b'E'));
+        let (re, im): (&str, &str) = match split {
+            Some(a) => imaginary.split_at(a),
+            None => ("0", imaginary),
+        };
+        let im: N = match im {
+            "" | "+" => N::from_integer(1),
+            "-" => N::from_integer(-1),
+            a => part(a.strip_prefix('+').unwrap_or(a))?,
+        };
+        return Ok(Complex::new(part(re)?, im))
+    }
+}
+
+// Printed as a single value without spaces, eg. 3+2i, -i or 1/2-1/3i, so it can be read back in.
+impl<N: Numeric> fmt::Display for Complex<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.im.is_zero() {
+            return write!(f, "{}", self.re)
+        }
+        if !self.re.is_zero() {
+            write!(f, "{}", self.re)?;
+            if !self.im.is_negative() {
+                f.write_str("+")?;
+            }
+        }
+        if self.im == N::from_integer(1) {
+            return f.write_str("i")
+        } else if self.im == N::from_integer(-1) {
+            return f.write_str("-i")
+        }
+        return write!(f, "{}i", self.im)
+    }
+}
+
+impl<N: Numeric> fmt::Debug for Complex<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self)
+    }
+}
+
+// With the serde feature a real number is serialized the way N is, and any other as a string, eg. "3+2i". Numbers are read back from
+// either.
+#[cfg(feature = "serde")]
+impl<N: Numeric + serde::Serialize> serde::Serialize for Complex<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.im.is_zero() {
+            return self.re.serialize(serializer)
+        }
+        return serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, N: Numeric> serde::Deserialize<'de> for Complex<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Complex<N>, D::Error> {
+        struct ComplexVisitor<N>(std::marker::PhantomData<N>);
+
+        impl<N: Numeric> serde::de::Visitor<'_> for ComplexVisitor<N> {
+            type Value = Complex<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                return f.write_str("a number, or a complex number such as \"3+2i\"")
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Complex<N>, E> {
+                return value.to_string().parse::<Complex<N>>().map_err(E::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Complex<N>, E> {
+                return value.to_string().parse::<Complex<N>>().map_err(E::custom)
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Complex<N>, E> {
+                return value.to_string().parse::<Complex<N>>().map_err(E::custom)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Complex<N>, E> {
+                return value.parse::<Complex<N>>().map_err(E::custom)
+            }
+        }
+
+        return deserializer.deserialize_any(ComplexVisitor(std::marker::PhantomData))
+    }
+}
@@ -0,0 +1,79 @@
+// Exporting a Node as a Graphviz DOT graph, to see the shape of the binary tree: how split_locater split the tokens up, and what
+// simplify_node changed. Operators are drawn as ellipses with an edge to each of their sides, and NUM and VAR values as boxes. The terms
+// of an Inequality are drawn the same way, with a dashed edge for each comparison between them.
+use super::{Inequality, Node};
+use super::numeric::Numeric;
+use super::token::Token;
+
+impl<N: Numeric> Node<N> {
+    /// The tree as a Graphviz DOT graph, eg. `dot -Tsvg` draws it.
+    pub fn to_dot(&self) -> String {
+        let mut dot: String = String::from("digraph {\n");
+        write_node(self, "n", &mut 0, "    ", &mut dot);
+        dot.push_str("}\n");
+        return dot
+    }
+}
+
+/// The tree before and after simplification side by side in one Graphviz DOT graph.
+pub fn comparison<N: Numeric>(before: &Node<N>, after: &Node<N>) -> String {
+    let mut dot: String = String::from("digraph {\n");
+    for (i, (label, node)) in [("Before simplification", before), ("After simplification", after)].iter().enumerate() {
+        dot.push_str(&format!("    subgraph cluster_{} {{\n        label=\"{}\";\n", i, label));
+        write_node(node, &format!("c{}_n", i), &mut 0, "        ", &mut dot);
+        dot.push_str("    }\n");
+    }
+    dot.push_str("}\n");
+    return dot
+}
+
+/// The terms of a chain of inequalities before and after simplification side by side in one Graphviz DOT graph.
+pub fn inequality_comparison<N: Numeric>(before: &Inequality<N>, after: &Inequality<N>) -> String {
+    let mut dot: String = String::from("digraph {\n");
+    for (i, (label_text, inequality)) in [("Before simplification", before), ("After simplification", after)].iter().enumerate() {
+        dot.push_str(&format!("    subgraph cluster_{} {{\n        label=\"{}\";\n", i, label_text));
+        let mut count: usize = 0;
+        let roots: Vec<String> = inequality.terms().iter().map(|a| write_node(a, &format!("c{}_n", i), &mut count, "        ", &mut dot)).collect();
+        for (j, comparison) in inequality.comparisons().iter().enumerate() {
+            dot.push_str(&format!("        {} -> {} [label=\"{}\", style=dashed];\n", roots[j], roots[j + 1], label(comparison)));
+        }
+        dot.push_str("    }\n");
+    }
+    dot.push_str("}\n");
+    return dot
+}
+
+// The label of a node, escaped for a DOT string.
+fn label<N: Numeric>(data_type: &Token<N>) -> String {
+    match data_type {
+        Token::ADD => return "+".to_string(),
+        Token::MUL => return "*".to_string(),
+        Token::DIV => return "/".to_string(),
+        Token::EXP => return "^".to_string(),
+        Token::LT => return "<".to_string(),
+        Token::LE => return "<=".to_string(),
+        Token::GT => return ">".to_string(),
+        Token::GE => return ">=".to_string(),
+        Token::NUM(a) => return a.to_string(),
+        Token::VAR(a) => return a.as_str().replace('\\', "\\\\").replace('"', "\\\""),
+        a => return format!("{:?}", a),
+    }
+}
+
+// Write a node and its branches, numbering them in the order they are written. Returns the id of the node.
+fn write_node<N: Numeric>(node: &Node<N>, prefix: &str, count: &mut usize, indent: &str, dot: &mut String) -> String {
+    let id: String = format!("{}{}", prefix, count);
+    *count += 1;
+    let shape: &str = match node.data_type {
+        Token::NUM(_) | Token::VAR(_) => "box",
+        _ => "ellipse",
+    };
+    dot.push_str(&format!("{}{} [label=\"{}\", shape={}];\n", indent, id, label(&node.data_type), shape));
+    for (side, branch) in [("left", &node.left), ("right", &node.right)] {
+        if let Some(a) = branch {
+            let branch_id: String = write_node(a, prefix, count, indent, dot);
+            dot.push_str(&format!("{}{} -> {} [label=\"{}\"];\n", indent, id, branch_id, side));
+        }
+    }
+    return id
+}
@@ -0,0 +1,51 @@
+// The ways reading an expression can fail. The tokenizer accepts anything (an unknown word is a VAR), so the errors all come from
+// matching the groups and splitting the tokens into a tree, or from reading an S-expression.
+use std::fmt;
+
+/// Why an expression couldn't be read.
+#[derive(Debug)]
+#[derive(Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// An LGROUP without an RGROUP to close it, or an RGROUP without an LGROUP, eg. `( x + 1`.
+    UnbalancedGroups,
+    /// Nothing where a value should be, eg. the right side of `x +`, or an empty input.
+    ExpectedValue,
+    /// Two values without an operator between them, eg. `x y`.
+    ExpectedOperator,
+    /// A comparison in an expression, which has to be read with `parse_inequality` instead, eg. `x < 1`.
+    UnexpectedComparison,
+    /// A comparison inside of a group, eg. `( 0 < x ) <= 1`.
+    ComparisonInGroup,
+    /// A chain of comparisons that don't all point the same way, eg. `0 < x > 5`.
+    MixedComparisons,
+    /// A list in an S-expression that doesn't start with one of `+`, `*`, `/` or `^`.
+    UnknownOperator(String),
+    /// A list in an S-expression that doesn't close after the two values of its operator.
+    ExpectedClose,
+    /// A `)` in an S-expression without a list to close.
+    UnexpectedClose,
+    /// A name in an S-expression that starts with a bar, but never ends with one.
+    UnclosedBar,
+    /// More than one value in an S-expression.
+    TrailingInput,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnbalancedGroups => return f.write_str("There must be an even number of group symbols!"),
+            ParseError::ExpectedValue => return f.write_str("Expected a value!"),
+            ParseError::ExpectedOperator => return f.write_str("Expected an operator between values!"),
+            ParseError::UnexpectedComparison => return f.write_str("Comparisons can only be read as a chain of inequalities!"),
+            ParseError::ComparisonInGroup => return f.write_str("Comparisons can't be inside of a group!"),
+            ParseError::MixedComparisons => return f.write_str("Chained comparisons must all point the same way!"),
+            ParseError::UnknownOperator(a) => return write!(f, "{} isn't an operator!", a),
+            ParseError::ExpectedClose => return f.write_str("Expected ) after the two values of an operator!"),
+            ParseError::UnexpectedClose => return f.write_str("Unexpected )!"),
+            ParseError::UnclosedBar => return f.write_str("Expected a closing |!"),
+            ParseError::TrailingInput => return f.write_str("Expected the S-expression to end after its first value!"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
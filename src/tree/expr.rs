@@ -0,0 +1,479 @@
+// The typed expression tree. Unlike Node, which reuses Token as its operator and carries left and right for every value, each kind of
+// expression only holds what it needs, so a NUM with children or an ADD without them can't be built. Sums and products hold any number
+// of operands, and division is stored as multiplication by a power of -1 (the same way subtraction is multiplication by -1).
+use super::Node;
+use super::assume::Assumptions;
+use super::order::cmp_stored;
+use super::numeric::Numeric;
+use super::rational::Rational;
+use super::store::{ExprId, ExprStore, Stored};
+use super::symbol::Symbol;
+use super::token::Token;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+#[derive(Debug)]
+#[derive(Clone)]
+pub enum Expr<N = Rational> {
+    Num(N),
+    Sym(Symbol),
+    Add(Vec<Expr<N>>),
+    Mul(Vec<Expr<N>>),
+    Pow(Box<Expr<N>>, Box<Expr<N>>),
+    Func(String, Vec<Expr<N>>),
+}
+
+impl<N: Numeric> Expr<N> {
+    /// Simplify the expression. Nested sums and products are flattened into a single sum or product and their operands are sorted,
+    /// so numbers can be folded and like terms collected no matter how the input was grouped, eg. x + 2 + x is 2 + 2*x.
+    pub fn simplify(&self) -> Expr<N> {
+        return self.simplify_with(&Assumptions::new())
+    }
+
+    /// Simplify the expression, also applying the rewrites that only hold under `assumptions`, eg. x / x is 1 once x is assumed to be
+    /// nonzero, and (x^2)^(1/2) is x once x is assumed to be positive.
+    pub fn simplify_with(&self, assumptions: &Assumptions) -> Expr<N> {
+        let mut simplifier: Simplify<N> = Simplify {
+            assumptions: assumptions,
+            store: ExprStore::new(),
+            simplified: HashMap::new(),
+        };
+        let id: ExprId = simplifier.store.insert(self);
+        let simplified: ExprId = simplifier.simplify(id);
+        return simplifier.store.to_expr(simplified)
+    }
+
+    /// Convert the expression back into a Node. Sums and products are chained to the left, the same way split_locater builds them, and
+    /// factors with a negative NUM exponent become a DIV. Functions have no Token, so an expression holding one can't be stored in a Node
+    /// and is an error.
+    pub fn to_node(&self) -> Result<Box<Node<N>>, String> {
+        match self {
+            Expr::Num(a) => return Ok(leaf(Token::NUM(a.clone()))),
+            Expr::Sym(a) => return Ok(leaf(Token::VAR(*a))),
+            Expr::Add(terms) => return chain(Token::ADD, terms, N::from_integer(0)),
+            Expr::Mul(factors) => {
+                let mut numerator: Vec<Expr<N>> = Vec::new();
+                let mut denominator: Vec<Expr<N>> = Vec::new();
+                for a in factors {
+                    match a {
+                        Expr::Pow(base, exponent) => match &**exponent {
+                            Expr::Num(b) if *b == N::from_integer(-1) => denominator.push((**base).clone()),
+                            Expr::Num(b) if b.is_negative() => denominator.push(Expr::Pow(base.clone(), Box::new(Expr::Num(-b.clone())))),
+                            _ => numerator.push(a.clone()),
+                        },
+                        _ => numerator.push(a.clone()),
+                    }
+                }
+                if denominator.is_empty() {
+                    return chain(Token::MUL, &numerator, N::from_integer(1))
+                }
+                return Ok(branch(Token::DIV, chain(Token::MUL, &numerator, N::from_integer(1))?, chain(Token::MUL, &denominator, N::from_integer(1))?))
+            }
+            Expr::Pow(base, exponent) => match &**exponent {
+                Expr::Num(a) if *a == N::from_integer(-1) => return Ok(branch(Token::DIV, leaf(Token::NUM(N::from_integer(1))), base.to_node()?)),
+                _ => return Ok(branch(Token::EXP, base.to_node()?, exponent.to_node()?)),
+            },
+            Expr::Func(name, _) => return Err(format!("The function {} can't be stored in a Node!", name)),
+        }
+    }
+}
+
+/// Convert an expression into a Node, see `Expr::to_node`. An expression holding a function is an error.
+impl<N: Numeric> TryFrom<&Expr<N>> for Node<N> {
+    type Error = String;
+
+    fn try_from(expr: &Expr<N>) -> Result<Node<N>, String> {
+        return expr.to_node().map(|a| *a)
+    }
+}
+
+// Simplifies from the bottom up, so every operand is already simplified when the expression holding it is. The expression is interned in
+// a store first, and the rules below work on the ids in it, so copying an operand is copying its id and two operands are the same
+// expression exactly when they have the same id. The id each id simplified to is kept, so a subexpression that appears more than once is
+// only simplified once, and the expression is only rebuilt as a tree once it is done.
+struct Simplify<'a, N: Numeric> {
+    assumptions: &'a Assumptions,
+    store: ExprStore<N>,
+    simplified: HashMap<ExprId, ExprId>,
+}
+
+impl<N: Numeric> Simplify<'_, N> {
+    fn simplify(&mut self, id: ExprId) -> ExprId {
+        if let Some(simplified) = self.simplified.get(&id) {
+            return *simplified
+        }
+        let simplified: ExprId = match self.store.get(id).clone() {
+            Stored::Num(_) | Stored::Sym(_) => return id,
+            Stored::Add(terms) => {
+                let terms: Vec<ExprId> = self.operands(&terms);
+                self.add(terms)
+            }
+            Stored::Mul(factors) => {
+                let factors: Vec<ExprId> = self.operands(&factors);
+                self.mul(factors)
+            }
+            Stored::Pow(base, exponent) => {
+                let base: ExprId = self.simplify(base);
+                let exponent: ExprId = self.simplify(exponent);
+                self.pow(base, exponent)
+            }
+            Stored::Func(name, arguments) => {
+                let arguments: Vec<ExprId> = self.operands(&arguments);
+                self.store.intern(Stored::Func(name, arguments))
+            }
+        };
+        self.simplified.insert(id, simplified);
+        return simplified
+    }
+
+    fn operands(&mut self, ids: &[ExprId]) -> Vec<ExprId> {
+        return ids.iter().map(|a| self.simplify(*a)).collect()
+    }
+
+    fn num(&mut self, num: N) -> ExprId {
+        return self.store.intern(Stored::Num(num))
+    }
+
+    // The NUM behind an id, if it is one.
+    fn number(&self, id: ExprId) -> Option<N> {
+        match self.store.get(id) {
+            Stored::Num(a) => return Some(a.clone()),
+            _ => return None,
+        }
+    }
+
+    fn cmp(&self, left: ExprId, right: ExprId) -> Ordering {
+        return cmp_stored(&self.store, left, right)
+    }
+
+    // Split a term of a sum into its NUM coefficient and the rest of it, eg. 3*x*y is 3 and x*y. The rest of a NUM is the empty product.
+    fn split_coefficient(&mut self, term: ExprId) -> (N, ExprId) {
+        match self.store.get(term).clone() {
+            Stored::Num(a) => return (a, self.store.intern(Stored::Mul(Vec::new()))),
+            Stored::Mul(factors) => {
+                if let Some(coefficient) = factors.first().and_then(|a| self.number(*a)) {
+                    if factors.len() == 2 {
+                        return (coefficient, factors[1])
+                    }
+                    return (coefficient, self.store.intern(Stored::Mul(factors[1..].to_vec())))
+                }
+                return (N::from_integer(1), term)
+            }
+            _ => return (N::from_integer(1), term),
+        }
+    }
+
+    // Split a factor of a product into its base and exponent, eg. x^2 is x and 2, and y is y and 1.
+    fn split_exponent(&mut self, factor: ExprId) -> (ExprId, ExprId) {
+        match self.store.get(factor) {
+            Stored::Pow(base, exponent) => return (*base, *exponent),
+            _ => return (factor, self.num(N::from_integer(1))),
+        }
+    }
+
+    fn add(&mut self, terms: Vec<ExprId>) -> ExprId {
+        // Flatten any sums inside of the sum
+        let mut flat: Vec<ExprId> = Vec::with_capacity(terms.len());
+        for a in terms {
+            match self.store.get(a) {
+                Stored::Add(inner) => flat.extend(inner.iter().copied()),
+                _ => flat.push(a),
+            }
+        }
+
+        // Sort the terms by what is left without their coefficients, so like terms end up next to each other, and then add the
+        // coefficients of like terms together. The NUM values are all like terms of each other (with the empty product as what is left.)
+        let mut split: Vec<(N, ExprId)> = flat.into_iter().map(|a| self.split_coefficient(a)).collect();
+        split.sort_by(|a, b| self.cmp(a.1, b.1));
+        let mut collected: Vec<(N, ExprId)> = Vec::with_capacity(split.len());
+        for (coefficient, rest) in split {
+            match collected.last_mut() {
+                Some(last) if last.1 == rest => last.0 += coefficient,
+                _ => collected.push((coefficient, rest)),
+            }
+        }
+
+        // Put the coefficients back, dropping the terms that cancelled out
+        let empty: ExprId = self.store.intern(Stored::Mul(Vec::new()));
+        let mut result: Vec<ExprId> = Vec::with_capacity(collected.len());
+        for (coefficient, rest) in collected {
+            if coefficient.is_zero() {
+                continue;
+            }
+            if rest == empty {
+                result.push(self.num(coefficient));
+            } else if coefficient == N::from_integer(1) {
+                result.push(rest);
+            } else {
+                let coefficient: ExprId = self.num(coefficient);
+                result.push(self.mul(vec![coefficient, rest]));
+            }
+        }
+        result.sort_by(|a, b| self.cmp(*a, *b));
+
+        match result.len() {
+            0 => return self.num(N::from_integer(0)),
+            1 => return result[0],
+            _ => return self.store.intern(Stored::Add(result)),
+        }
+    }
+
+    fn mul(&mut self, factors: Vec<ExprId>) -> ExprId {
+        // Flatten any products inside of the product, multiplying all of the NUM values together as we go
+        let mut coefficient: N = N::from_integer(1);
+        let mut flat: Vec<ExprId> = Vec::with_capacity(factors.len());
+        for a in factors {
+            self.push_factor(a, &mut coefficient, &mut flat);
+        }
+        if coefficient.is_zero() && !flat.iter().any(|a| self.divides_zero(*a)) {
+            return self.num(N::from_integer(0))
+        }
+
+        // Sort the factors by their base, so like bases end up next to each other, and then add the exponents of like bases together.
+        let mut split: Vec<(ExprId, ExprId)> = flat.into_iter().map(|a| self.split_exponent(a)).collect();
+        split.sort_by(|a, b| self.cmp(a.0, b.0));
+        let mut collected: Vec<(ExprId, Vec<ExprId>)> = Vec::with_capacity(split.len());
+        for (base, exponent) in split {
+            match collected.last_mut() {
+                Some(last) if last.0 == base => last.1.push(exponent),
+                _ => collected.push((base, vec![exponent])),
+            }
+        }
+
+        // Put the exponents back, any NUM values that come out of it join the coefficient
+        let mut powers: Vec<ExprId> = Vec::with_capacity(collected.len());
+        for (base, exponents) in collected {
+            for exponent in self.group_exponents(base, exponents) {
+                let power: ExprId = self.pow(base, exponent);
+                self.push_factor(power, &mut coefficient, &mut powers);
+            }
+        }
+
+        // Powers of positive NUM values to the same NUM exponent share it, eg. 2^(1/2) * 3^(1/2) is 6^(1/2), which splits up again where
+        // it can, eg. 2^(1/2) * 6^(1/2) is 2 * 3^(1/2)
+        let mut result: Vec<ExprId> = Vec::with_capacity(powers.len());
+        let mut radicals: Vec<(N, N)> = Vec::new();
+        for a in powers {
+            let radical: Option<(N, N)> = match self.store.get(a) {
+                Stored::Pow(base, exponent) => match (self.number(*base), self.number(*exponent)) {
+                    (Some(b), Some(c)) if b.is_positive() => Some((b, c)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match radical {
+                Some((b, c)) => match radicals.iter_mut().find(|d| d.0 == c) {
+                    Some(d) => d.1 *= b,
+                    None => radicals.push((c, b)),
+                },
+                None => result.push(a),
+            }
+        }
+        for (exponent, base) in radicals {
+            let base: ExprId = self.num(base);
+            let exponent: ExprId = self.num(exponent);
+            let power: ExprId = self.pow(base, exponent);
+            self.push_factor(power, &mut coefficient, &mut result);
+        }
+        if coefficient.is_zero() && !result.iter().any(|a| self.divides_zero(*a)) {
+            return self.num(N::from_integer(0))
+        }
+        if coefficient != N::from_integer(1) || result.is_empty() {
+            result.push(self.num(coefficient));
+        }
+        result.sort_by(|a, b| self.cmp(*a, *b));
+
+        match result.len() {
+            1 => return result[0],
+            _ => return self.store.intern(Stored::Mul(result)),
+        }
+    }
+
+    // Whether the factor is 0 raised to a negative power, which is left as it is (see Numeric::pow), so a product with it isn't 0
+    // either, eg. 0 / 0.
+    fn divides_zero(&self, factor: ExprId) -> bool {
+        match self.store.get(factor) {
+            Stored::Pow(base, exponent) => match (self.number(*base), self.number(*exponent)) {
+                (Some(a), Some(b)) => return a.is_zero() && b.is_negative(),
+                _ => return false,
+            },
+            _ => return false,
+        }
+    }
+
+    // Add a factor to a product, with any NUM values in it joining the coefficient, and the factors of a product in it joining the
+    // product.
+    fn push_factor(&self, factor: ExprId, coefficient: &mut N, factors: &mut Vec<ExprId>) {
+        match self.store.get(factor) {
+            Stored::Num(a) => *coefficient *= a.clone(),
+            Stored::Mul(inner) => {
+                for a in inner {
+                    match self.store.get(*a) {
+                        Stored::Num(b) => *coefficient *= b.clone(),
+                        _ => factors.push(*a),
+                    }
+                }
+            }
+            _ => factors.push(factor),
+        }
+    }
+
+    fn sum(&mut self, exponents: Vec<ExprId>) -> ExprId {
+        if exponents.len() == 1 {
+            return exponents[0]
+        }
+        return self.add(exponents)
+    }
+
+    // Add together the exponents of like bases. Adding a positive and a negative exponent divides the base out, eg. x * x^-1 is x^0,
+    // which is only 1 when x isn't 0. So unless the base is assumed to be nonzero, only the positive exponents are added together, and the
+    // negative NUM exponents together, and the two (along with any other exponents) are kept apart.
+    fn group_exponents(&mut self, base: ExprId, exponents: Vec<ExprId>) -> Vec<ExprId> {
+        if exponents.len() == 1 || self.assumptions.is_nonzero_in(&self.store, base) {
+            return vec![self.sum(exponents)]
+        }
+
+        let mut positive: Vec<ExprId> = Vec::new();
+        let mut negative: Vec<ExprId> = Vec::new();
+        let mut grouped: Vec<ExprId> = Vec::new();
+        for a in exponents {
+            if self.assumptions.is_positive_in(&self.store, a) {
+                positive.push(a);
+            } else if self.number(a).is_some_and(|b| b.is_negative()) {
+                negative.push(a);
+            } else {
+                grouped.push(a);
+            }
+        }
+        if !negative.is_empty() {
+            let sum: ExprId = self.sum(negative);
+            grouped.insert(0, sum);
+        }
+        if !positive.is_empty() {
+            let sum: ExprId = self.sum(positive);
+            grouped.insert(0, sum);
+        }
+        return grouped
+    }
+
+    fn pow(&mut self, base: ExprId, exponent: ExprId) -> ExprId {
+        let one: N = N::from_integer(1);
+        match (self.number(base), self.number(exponent)) {
+            // Raise the NUM to the power of the other NUM, unless the result can't be stored (eg. 2^(1/2)), which is left as it is
+            (Some(a), Some(b)) => {
+                if let Some(c) = a.pow(&b) {
+                    return self.num(c)
+                }
+                // An exact factor comes out of a power that isn't exact, eg. 12^(1/2) is 2 * 3^(1/2)
+                if let Some((coefficient, root, power)) = a.split_root(&b) {
+                    let root: ExprId = self.num(root);
+                    let power: ExprId = self.num(power);
+                    let factors: Vec<ExprId> = vec![self.num(coefficient), self.store.intern(Stored::Pow(root, power))];
+                    return self.mul(factors)
+                }
+                // A negative NUM splits into -1 and its absolute value when that power of -1 can be worked out, eg. with complex numbers
+                // (-3)^(1/2) is i * 3^(1/2)
+                if a.is_negative() {
+                    if let Some(c) = N::from_integer(-1).pow(&b) {
+                        let absolute: ExprId = self.num(-a);
+                        let factors: Vec<ExprId> = vec![self.num(c), self.pow(absolute, exponent)];
+                        return self.mul(factors)
+                    }
+                }
+                return self.store.intern(Stored::Pow(base, exponent))
+            }
+            (_, Some(b)) if b.is_zero() => return self.num(one),
+            (_, Some(b)) if b == one => return base,
+            (Some(a), _) if a == one => return self.num(one),
+            _ => {}
+        }
+        // Multiply the exponents of a power of a power, which only holds for an integer outer exponent, or a positive base (otherwise eg.
+        // (x^2)^(1/2) would be x when it is really |x|)
+        if let Stored::Pow(a, b) = *self.store.get(base) {
+            if self.assumptions.is_integer_in(&self.store, exponent) || self.assumptions.is_positive_in(&self.store, a) {
+                let exponent: ExprId = self.mul(vec![b, exponent]);
+                return self.pow(a, exponent)
+            }
+        }
+        return self.store.intern(Stored::Pow(base, exponent))
+    }
+}
+
+fn leaf<N: Numeric>(data_type: Token<N>) -> Box<Node<N>> {
+    return Box::new(Node {
+        data_type: data_type,
+        left: None,
+        right: None,
+    })
+}
+
+fn branch<N: Numeric>(data_type: Token<N>, left: Box<Node<N>>, right: Box<Node<N>>) -> Box<Node<N>> {
+    return Box::new(Node {
+        data_type: data_type,
+        left: Some(left),
+        right: Some(right),
+    })
+}
+
+// Chain the operands to the left with data_type, an empty chain is the identity of the operation.
+fn chain<N: Numeric>(data_type: Token<N>, operands: &[Expr<N>], identity: N) -> Result<Box<Node<N>>, String> {
+    let mut operands = operands.iter();
+    let mut node: Box<Node<N>> = match operands.next() {
+        Some(a) => a.to_node()?,
+        None => return Ok(leaf(Token::NUM(identity))),
+    };
+    for a in operands {
+        node = branch(data_type.clone(), node, a.to_node()?);
+    }
+    return Ok(node)
+}
+
+/// Convert a Node into an expression. A chain of ADD (or MUL) becomes a single sum (or product) of the operands along it, in the same
+/// order, and a DIV becomes the left side multiplied by the right side to the power of -1.
+impl<N: Numeric> From<&Node<N>> for Expr<N> {
+    fn from(node: &Node<N>) -> Expr<N> {
+        let side = |side: &Option<Box<Node<N>>>| -> Expr<N> {
+            match side {
+                Some(a) => return Expr::from(&**a),
+                None => panic!("The operator {:?} is missing a value!", node.data_type),
+            }
+        };
+        match &node.data_type {
+            Token::NUM(a) => return Expr::Num(a.clone()),
+            Token::VAR(a) => return Expr::Sym(*a),
+            Token::ADD => return Expr::Add(chain_operands(node)),
+            Token::MUL => return Expr::Mul(chain_operands(node)),
+            Token::DIV => return Expr::Mul(vec![side(&node.left), Expr::Pow(Box::new(side(&node.right)), Box::new(Expr::Num(N::from_integer(-1))))]),
+            Token::EXP => return Expr::Pow(Box::new(side(&node.left)), Box::new(side(&node.right))),
+            a => panic!("{:?} can't be part of an expression!", a),
+        }
+    }
+}
+
+// The operands along a chain of the same ADD or MUL, from left to right. A long sum or product is a chain as deep as it is long, so it
+// is followed with a stack of the nodes still to go rather than recursively.
+fn chain_operands<N: Numeric>(node: &Node<N>) -> Vec<Expr<N>> {
+    let mut operands: Vec<Expr<N>> = Vec::new();
+    let mut pending: Vec<&Node<N>> = vec![node];
+    while let Some(a) = pending.pop() {
+        if a.data_type != node.data_type {
+            operands.push(Expr::from(a));
+            continue;
+        }
+        match (&a.left, &a.right) {
+            (Some(left), Some(right)) => {
+                pending.push(right);
+                pending.push(left);
+            }
+            _ => panic!("The operator {:?} is missing a value!", a.data_type),
+        }
+    }
+    return operands
+}
+
+impl<N: Numeric> From<Node<N>> for Expr<N> {
+    fn from(node: Node<N>) -> Expr<N> {
+        return Expr::from(&node)
+    }
+}
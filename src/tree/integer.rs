@@ -0,0 +1,721 @@
+// Arbitrary-precision integers, the numerators and denominators of Rational, so exact numbers never overflow (eg. 2^100). An
+// Integer that fits in an i64 is stored as one, and only larger values are stored as a sign and a list of base 2^32 digits. Each value
+// has a single representation, so two equal integers are always equal field by field, and can be hashed and matched on.
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+// The divisors split_root tries, which are all below this.
+const TRIAL_DIVISORS: u64 = 1 << 12;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Integer {
+    value: Value,
+}
+
+// Big is only used for values outside of the i64 range. It holds whether the value is negative, and the digits of its magnitude least
+// significant first, without any leading zeros.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Value {
+    Small(i64),
+    Big(bool, Vec<u32>),
+}
+
+// The digits of a magnitude, least significant first, without any leading zeros.
+fn trim(mut digits: Vec<u32>) -> Vec<u32> {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    return digits
+}
+
+fn cmp_digits(left: &[u32], right: &[u32]) -> Ordering {
+    if left.len() != right.len() {
+        return left.len().cmp(&right.len())
+    }
+    for (a, b) in left.iter().rev().zip(right.iter().rev()) {
+        if a != b {
+            return a.cmp(b)
+        }
+    }
+    return Ordering::Equal
+}
+
+fn add_digits(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut sum: Vec<u32> = Vec::with_capacity(left.len().max(right.len()) + 1);
+    let mut carry: u64 = 0;
+    for i in 0..left.len().max(right.len()) {
+        let total: u64 = *left.get(i).unwrap_or(&0) as u64 + *right.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(total as u32);
+        carry = total >> 32;
+    }
+    sum.push(carry as u32);
+    return trim(sum)
+}
+
+// Subtract a magnitude from one that is at least as large.
+fn sub_digits(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut difference: Vec<u32> = Vec::with_capacity(left.len());
+    let mut borrow: i64 = 0;
+    for (i, a) in left.iter().enumerate() {
+        let mut total: i64 = *a as i64 - *right.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if total < 0 {
+            total += 1 << 32;
+            borrow = 1;
+        }
+        difference.push(total as u32);
+    }
+    return trim(difference)
+}
+
+fn mul_digits(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut product: Vec<u32> = vec![0; left.len() + right.len()];
+    for (i, a) in left.iter().enumerate() {
+        let mut carry: u64 = 0;
+        for (j, b) in right.iter().enumerate() {
+            let total: u64 = *a as u64 * *b as u64 + product[i + j] as u64 + carry;
+            product[i + j] = total as u32;
+            carry = total >> 32;
+        }
+        product[i + right.len()] = carry as u32;
+    }
+    return trim(product)
+}
+
+// Divide a magnitude by a single digit, giving the quotient and the remainder.
+fn divrem_digit(left: &[u32], right: u32) -> (Vec<u32>, u32) {
+    let mut quotient: Vec<u32> = vec![0; left.len()];
+    let mut remainder: u64 = 0;
+    for i in (0..left.len()).rev() {
+        let total: u64 = (remainder << 32) | left[i] as u64;
+        quotient[i] = (total / right as u64) as u32;
+        remainder = total % right as u64;
+    }
+    return (trim(quotient), remainder as u32)
+}
+
+// The digits shifted left by fewer than 32 bits, with one more digit on the end for the bits shifted out.
+fn shl_digits(digits: &[u32], shift: u32) -> Vec<u32> {
+    let mut shifted: Vec<u32> = Vec::with_capacity(digits.len() + 1);
+    let mut carry: u32 = 0;
+    for a in digits {
+        shifted.push((a << shift) | carry);
+        carry = if shift == 0 { 0 } else { a >> (32 - shift) };
+    }
+    shifted.push(carry);
+    return shifted
+}
+
+// Long division (Knuth's algorithm D), giving the quotient and the remainder. Each digit of the quotient is estimated from the top two
+// digits of what is left, which is off by at most 2 once the divisor is shifted to have its top bit set.
+fn divrem_digits(left: &[u32], right: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_digits(left, right) == Ordering::Less {
+        return (Vec::new(), left.to_vec())
+    }
+    if right.len() == 1 {
+        let (quotient, remainder) = divrem_digit(left, right[0]);
+        return (quotient, trim(vec![remainder]))
+    }
+    let shift: u32 = right[right.len() - 1].leading_zeros();
+    let divisor: Vec<u32> = trim(shl_digits(right, shift));
+    let mut remainder: Vec<u32> = shl_digits(left, shift);
+    let n: usize = divisor.len();
+    let mut quotient: Vec<u32> = vec![0; left.len() - n + 1];
+    for j in (0..quotient.len()).rev() {
+        let top: u64 = (remainder[j + n] as u64) << 32 | remainder[j + n - 1] as u64;
+        let mut estimate: u64 = top / divisor[n - 1] as u64;
+        let mut rest: u64 = top % divisor[n - 1] as u64;
+        while estimate >> 32 != 0 || estimate * divisor[n - 2] as u64 > (rest << 32 | remainder[j + n - 2] as u64) {
+            estimate -= 1;
+            rest += divisor[n - 1] as u64;
+            if rest >> 32 != 0 {
+                break;
+            }
+        }
+
+        // Subtract the estimate times the divisor
+        let mut borrow: i64 = 0;
+        let mut carry: u64 = 0;
+        for i in 0..n {
+            let product: u64 = estimate * divisor[i] as u64 + carry;
+            carry = product >> 32;
+            let total: i64 = remainder[i + j] as i64 - borrow - (product & 0xFFFF_FFFF) as i64;
+            remainder[i + j] = total as u32;
+            borrow = if total < 0 { 1 } else { 0 };
+        }
+        let total: i64 = remainder[j + n] as i64 - borrow - carry as i64;
+        remainder[j + n] = total as u32;
+
+        // The estimate was one too large, so add the divisor back
+        if total < 0 {
+            estimate -= 1;
+            let mut carry: u64 = 0;
+            for i in 0..n {
+                let sum: u64 = remainder[i + j] as u64 + divisor[i] as u64 + carry;
+                remainder[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = estimate as u32;
+    }
+
+    // Shift the remainder back
+    let mut unshifted: Vec<u32> = Vec::with_capacity(n);
+    for i in 0..n {
+        let digits: u64 = (remainder[i + 1] as u64) << 32 | remainder[i] as u64;
+        unshifted.push((digits >> shift) as u32);
+    }
+    return (trim(quotient), trim(unshifted))
+}
+
+impl Integer {
+    pub const ZERO: Integer = Integer { value: Value::Small(0) };
+    pub const ONE: Integer = Integer { value: Value::Small(1) };
+    pub const NEG_ONE: Integer = Integer { value: Value::Small(-1) };
+
+    // Build an integer from its sign and magnitude, storing it as an i64 when it fits.
+    fn from_digits(negative: bool, digits: Vec<u32>) -> Integer {
+        let digits: Vec<u32> = trim(digits);
+        if digits.len() <= 2 {
+            let magnitude: i128 = *digits.first().unwrap_or(&0) as i128 | (*digits.get(1).unwrap_or(&0) as i128) << 32;
+            let signed: i128 = if negative { -magnitude } else { magnitude };
+            if let Ok(a) = i64::try_from(signed) {
+                return Integer { value: Value::Small(a) }
+            }
+        }
+        return Integer { value: Value::Big(negative, digits) }
+    }
+
+    // The sign and magnitude of the integer.
+    fn to_digits(&self) -> (bool, Vec<u32>) {
+        match &self.value {
+            Value::Small(a) => {
+                let magnitude: u64 = a.unsigned_abs();
+                return (*a < 0, trim(vec![magnitude as u32, (magnitude >> 32) as u32]))
+            }
+            Value::Big(negative, digits) => return (*negative, digits.clone()),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.value == Value::Small(0)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        match &self.value {
+            Value::Small(a) => return *a < 0,
+            Value::Big(negative, _) => return *negative,
+        }
+    }
+
+    pub fn is_positive(&self) -> bool {
+        return !self.is_zero() && !self.is_negative()
+    }
+
+    pub fn abs(&self) -> Integer {
+        if self.is_negative() {
+            return -self
+        }
+        return self.clone()
+    }
+
+    /// The number of bits in the magnitude of the integer, 0 for 0.
+    pub fn bits(&self) -> u64 {
+        let (_, digits) = self.to_digits();
+        match digits.last() {
+            Some(a) => return (digits.len() as u64 - 1) * 32 + (32 - a.leading_zeros()) as u64,
+            None => return 0,
+        }
+    }
+
+    /// The number of 0 bits at the end of the integer, 0 for 0.
+    pub fn trailing_zeros(&self) -> u64 {
+        let (_, digits) = self.to_digits();
+        match digits.iter().position(|a| *a != 0) {
+            Some(i) => return i as u64 * 32 + digits[i].trailing_zeros() as u64,
+            None => return 0,
+        }
+    }
+
+    /// The integer multiplied by 2^bits.
+    pub fn shl(&self, bits: u64) -> Integer {
+        let (negative, digits) = self.to_digits();
+        let mut shifted: Vec<u32> = vec![0; (bits / 32) as usize];
+        shifted.extend(shl_digits(&digits, (bits % 32) as u32));
+        return Integer::from_digits(negative, shifted)
+    }
+
+    /// The integer divided by 2^bits, rounded towards 0.
+    pub fn shr(&self, bits: u64) -> Integer {
+        let (negative, digits) = self.to_digits();
+        let skip: usize = (bits / 32) as usize;
+        let shift: u32 = (bits % 32) as u32;
+        if skip >= digits.len() {
+            return Integer::ZERO
+        }
+        let mut shifted: Vec<u32> = Vec::with_capacity(digits.len() - skip);
+        for i in skip..digits.len() {
+            let high: u64 = *digits.get(i + 1).unwrap_or(&0) as u64;
+            shifted.push((((high << 32) | digits[i] as u64) >> shift) as u32);
+        }
+        return Integer::from_digits(negative, shifted)
+    }
+
+    /// The quotient and remainder of dividing by `other`, with the quotient rounded towards 0 (so the remainder has the sign of the
+    /// integer, like i64's / and %).
+    pub fn div_rem(&self, other: &Integer) -> (Integer, Integer) {
+        if other.is_zero() {
+            panic!("Can't divide by 0!");
+        }
+        if let (Value::Small(a), Value::Small(b)) = (&self.value, &other.value) {
+            if let (Some(quotient), Some(remainder)) = (a.checked_div(*b), a.checked_rem(*b)) {
+                return (Integer::from(quotient), Integer::from(remainder))
+            }
+        }
+        let (left_negative, left) = self.to_digits();
+        let (right_negative, right) = other.to_digits();
+        let (quotient, remainder) = divrem_digits(&left, &right);
+        return (Integer::from_digits(left_negative != right_negative, quotient), Integer::from_digits(left_negative, remainder))
+    }
+
+    /// The greatest common divisor, which is never negative.
+    pub fn gcd(&self, other: &Integer) -> Integer {
+        let mut a: Integer = self.abs();
+        let mut b: Integer = other.abs();
+        while !b.is_zero() {
+            let c: Integer = a.div_rem(&b).1;
+            a = b;
+            b = c;
+        }
+        return a
+    }
+
+    /// The integer raised to a power.
+    pub fn pow(&self, exponent: u32) -> Integer {
+        let mut base: Integer = self.clone();
+        let mut exponent: u32 = exponent;
+        let mut result: Integer = Integer::ONE;
+        // Square the base for each binary digit of the exponent
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        return result
+    }
+
+    /// The n-th root of the integer, if it is a perfect n-th power, eg. the cube root of -27 is -3 but 2 has no square root.
+    pub fn root(&self, n: u32) -> Option<Integer> {
+        if n == 0 {
+            return None
+        }
+        if self.is_negative() {
+            if n & 1 == 0 {
+                return None
+            }
+            return self.abs().root(n).map(|a| -a)
+        }
+        if self.is_zero() || n == 1 {
+            return Some(self.clone())
+        }
+        // A root of a degree at least the number of bits is below 2, so only 1 has one
+        if n as u64 >= self.bits() {
+            if self == &Integer::ONE {
+                return Some(Integer::ONE)
+            }
+            return None
+        }
+        // Newton's method from above the root, which decreases until it reaches the floor of the root
+        let degree: Integer = Integer::from(n as i64);
+        let mut root: Integer = Integer::from(2).pow((self.bits() / n as u64 + 1) as u32);
+        loop {
+            let next: Integer = (&(&(&degree - &Integer::ONE) * &root) + &(self / &root.pow(n - 1))) / degree.clone();
+            if next >= root {
+                break;
+            }
+            root = next;
+        }
+        if &root.pow(n) == self {
+            return Some(root)
+        }
+        return None
+    }
+
+    /// The positive integer as s^n * t, with s as large as can be found, eg. 72 is 3^2 * 8 for n = 2. Only factors below 2^12 are
+    /// split out one by one, and a larger factor only if what is left after them is a perfect n-th power.
+    pub fn split_root(&self, n: u32) -> (Integer, Integer) {
+        let mut root: Integer = Integer::ONE;
+        let mut kept: Integer = Integer::ONE;
+        let mut rest: Integer = self.clone();
+        let mut divisor: u64 = 2;
+        // Stop once divisor^n is past what is left, which has no factors below the divisor by then
+        while divisor < TRIAL_DIVISORS && (63 - divisor.leading_zeros()) as u64 * (n as u64) < rest.bits() {
+            let factor: Integer = Integer::from(divisor);
+            let mut count: u32 = 0;
+            loop {
+                let (quotient, remainder): (Integer, Integer) = rest.div_rem(&factor);
+                if !remainder.is_zero() {
+                    break;
+                }
+                rest = quotient;
+                count += 1;
+            }
+            root = &root * &factor.pow(count / n);
+            kept = &kept * &factor.pow(count % n);
+            // 2, and then the odd numbers
+            divisor += 1 + (divisor & 1);
+        }
+        match rest.root(n) {
+            Some(a) => root = &root * &a,
+            None => kept = &kept * &rest,
+        }
+        return (root, kept)
+    }
+
+    /// The integer as an i64, if it fits in one.
+    pub fn to_i64(&self) -> Option<i64> {
+        match &self.value {
+            Value::Small(a) => return Some(*a),
+            Value::Big(_, _) => return None,
+        }
+    }
+
+    /// The integer as the nearest f64, which is infinite if it is out of the f64 range.
+    pub fn to_f64(&self) -> f64 {
+        match &self.value {
+            Value::Small(a) => return *a as f64,
+            Value::Big(negative, digits) => {
+                let magnitude: f64 = digits.iter().rev().fold(0.0, |total, a| total * 4294967296.0 + *a as f64);
+                if *negative {
+                    return -magnitude
+                }
+                return magnitude
+            }
+        }
+    }
+}
+
+impl From<i32> for Integer {
+    fn from(value: i32) -> Integer {
+        return Integer { value: Value::Small(value as i64) }
+    }
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Integer {
+        return Integer { value: Value::Small(value) }
+    }
+}
+
+impl From<u64> for Integer {
+    fn from(value: u64) -> Integer {
+        return Integer::from_digits(false, vec![value as u32, (value >> 32) as u32])
+    }
+}
+
+impl PartialOrd for Integer {
+    fn partial_cmp(&self, other: &Integer) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Integer {
+    fn cmp(&self, other: &Integer) -> Ordering {
+        if let (Value::Small(a), Value::Small(b)) = (&self.value, &other.value) {
+            return a.cmp(b)
+        }
+        let (left_negative, left) = self.to_digits();
+        let (right_negative, right) = other.to_digits();
+        match (left_negative, right_negative) {
+            (false, true) => return Ordering::Greater,
+            (true, false) => return Ordering::Less,
+            (false, false) => return cmp_digits(&left, &right),
+            (true, true) => return cmp_digits(&right, &left),
+        }
+    }
+}
+
+impl Add for &Integer {
+    type Output = Integer;
+
+    fn add(self, other: &Integer) -> Integer {
+        if let (Value::Small(a), Value::Small(b)) = (&self.value, &other.value) {
+            if let Some(c) = a.checked_add(*b) {
+                return Integer::from(c)
+            }
+        }
+        let (left_negative, left) = self.to_digits();
+        let (right_negative, right) = other.to_digits();
+        if left_negative == right_negative {
+            return Integer::from_digits(left_negative, add_digits(&left, &right))
+        }
+        // The signs differ, so the result has the sign of the larger magnitude
+        match cmp_digits(&left, &right) {
+            Ordering::Less => return Integer::from_digits(right_negative, sub_digits(&right, &left)),
+            _ => return Integer::from_digits(left_negative, sub_digits(&left, &right)),
+        }
+    }
+}
+
+impl Sub for &Integer {
+    type Output = Integer;
+
+    fn sub(self, other: &Integer) -> Integer {
+        return self + &-other
+    }
+}
+
+impl Mul for &Integer {
+    type Output = Integer;
+
+    fn mul(self, other: &Integer) -> Integer {
+        if let (Value::Small(a), Value::Small(b)) = (&self.value, &other.value) {
+            if let Some(c) = a.checked_mul(*b) {
+                return Integer::from(c)
+            }
+        }
+        let (left_negative, left) = self.to_digits();
+        let (right_negative, right) = other.to_digits();
+        return Integer::from_digits(left_negative != right_negative, mul_digits(&left, &right))
+    }
+}
+
+impl Div for &Integer {
+    type Output = Integer;
+
+    fn div(self, other: &Integer) -> Integer {
+        return self.div_rem(other).0
+    }
+}
+
+impl Rem for &Integer {
+    type Output = Integer;
+
+    fn rem(self, other: &Integer) -> Integer {
+        return self.div_rem(other).1
+    }
+}
+
+impl Neg for &Integer {
+    type Output = Integer;
+
+    fn neg(self) -> Integer {
+        match &self.value {
+            Value::Small(a) => match a.checked_neg() {
+                Some(b) => return Integer::from(b),
+                None => return Integer::from_digits(false, vec![0, 1 << 31]),
+            },
+            Value::Big(negative, digits) => return Integer::from_digits(!negative, digits.clone()),
+        }
+    }
+}
+
+impl Neg for Integer {
+    type Output = Integer;
+
+    fn neg(self) -> Integer {
+        return -&self
+    }
+}
+
+// The operators on owned integers, which borrow them for the operators above.
+macro_rules! owned_operators {
+    ($($operator:ident $method:ident),*) => {
+        $(
+            impl $operator for Integer {
+                type Output = Integer;
+
+                fn $method(self, other: Integer) -> Integer {
+                    return (&self).$method(&other)
+                }
+            }
+
+            impl $operator<&Integer> for Integer {
+                type Output = Integer;
+
+                fn $method(self, other: &Integer) -> Integer {
+                    return (&self).$method(other)
+                }
+            }
+        )*
+    };
+}
+
+owned_operators!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+/// Reads the decimal digits of an integer, with an optional sign, eg. `-1267650600228229401496703205376`.
+impl FromStr for Integer {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Integer, String> {
+        let (negative, unsigned): (bool, &str) = match input.strip_prefix('-') {
+            Some(a) => (true, a),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+        if unsigned.is_empty() || !unsigned.bytes().all(|a| a.is_ascii_digit()) {
+            return Err(format!("{} isn't an integer!", input))
+        }
+        // Read nine digits at a time, the most that fit in one base 2^32 digit
+        let mut digits: Vec<u32> = Vec::new();
+        for chunk in unsigned.as_bytes().rchunks(9).rev() {
+            let value: u64 = chunk.iter().fold(0, |total, a| total * 10 + (a - b'0') as u64);
+            let scale: u64 = 10u64.pow(chunk.len() as u32);
+            let mut carry: u64 = value;
+            for digit in digits.iter_mut() {
+                let total: u64 = *digit as u64 * scale + carry;
+                *digit = total as u32;
+                carry = total >> 32;
+            }
+            if carry != 0 {
+                digits.push(carry as u32);
+            }
+        }
+        return Ok(Integer::from_digits(negative, digits))
+    }
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (negative, mut digits) = match &self.value {
+            Value::Small(a) => return write!(f, "{}", a),
+            Value::Big(negative, digits) => (*negative, digits.clone()),
+        };
+        // Take nine decimal digits at a time off the end
+        let mut chunks: Vec<u32> = Vec::new();
+        while !digits.is_empty() {
+            let (quotient, remainder) = divrem_digit(&digits, 1_000_000_000);
+            chunks.push(remainder);
+            digits = quotient;
+        }
+        let mut decimal: String = if negative { "-".to_string() } else { String::new() };
+        for (i, chunk) in chunks.iter().rev().enumerate() {
+            if i == 0 {
+                decimal.push_str(&chunk.to_string());
+            } else {
+                decimal.push_str(&format!("{:09}", chunk));
+            }
+        }
+        return f.write_str(&decimal)
+    }
+}
+
+impl fmt::Debug for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(value: i128) -> Integer {
+        return value.to_string().parse().unwrap()
+    }
+
+    // Values spread over the whole range up to 2^bits, with the small values and the edges of the i64 range among them.
+    fn values(bits: u32) -> Vec<i128> {
+        let mut values: Vec<i128> = vec![0, 1, 2, 3, 7, i64::MAX as i128, i64::MAX as i128 + 1, i64::MIN as i128, (1 << 32) - 1, 1 << 32];
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            // xorshift64, taking a random number of bits from two steps of it
+            let mut next = || -> u64 {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                return state
+            };
+            let length: u32 = (next() % bits as u64) as u32 + 1;
+            let value: u128 = ((next() as u128) << 64 | next() as u128) >> (128 - length);
+            values.push(value as i128);
+        }
+        let negative: Vec<i128> = values.iter().map(|a| -a).collect();
+        values.extend(negative);
+        return values
+    }
+
+    #[test]
+    fn mul() {
+        for a in values(62) {
+            for b in values(62).iter().step_by(7) {
+                assert_eq!(&big(a) * &big(*b), big(a * b), "{} * {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn div_rem() {
+        for a in values(126) {
+            for b in values(126).iter().step_by(7).filter(|b| **b != 0) {
+                assert_eq!(big(a).div_rem(&big(*b)), (big(a / b), big(a % b)), "{} / {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn multi_digit_quotients() {
+        // Divisors of two and three digits, which take the long division with a quotient digit estimate that can be off
+        let dividend: i128 = 0x7fff_ffff_0000_0001_ffff_ffff_0000_0001;
+        for divisor in [0x1_0000_0001_i128, 0xffff_ffff_ffff_ffff, 0x8000_0000_0000_0001, 0x1_ffff_ffff_ffff_ffff_ffff, 0x7fff_ffff_8000_0000_0000_0001] {
+            assert_eq!(big(dividend).div_rem(&big(divisor)), (big(dividend / divisor), big(dividend % divisor)), "{}", divisor);
+        }
+        let large: Integer = Integer::from(3).pow(200);
+        let divisor: Integer = Integer::from(7).pow(40);
+        let (quotient, remainder): (Integer, Integer) = large.div_rem(&divisor);
+        assert_eq!(&(&quotient * &divisor) + &remainder, large);
+        assert!(remainder < divisor);
+    }
+
+    #[test]
+    fn gcd() {
+        let gcd = |mut a: i128, mut b: i128| -> i128 {
+            while b != 0 {
+                let c: i128 = a % b;
+                a = b;
+                b = c;
+            }
+            return a.abs()
+        };
+        for a in values(100).iter().step_by(3) {
+            for b in values(100).iter().step_by(5) {
+                assert_eq!(big(*a).gcd(&big(*b)), big(gcd(*a, *b)), "gcd({}, {})", a, b);
+            }
+        }
+        assert_eq!(Integer::from(6).pow(50).gcd(&Integer::from(15).pow(40)), Integer::from(3).pow(40));
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(Integer::from(-27).root(3), Some(Integer::from(-3)));
+        assert_eq!(Integer::from(-4).root(2), None);
+        assert_eq!(Integer::from(2).root(2), None);
+        assert_eq!(Integer::ONE.root(5), Some(Integer::ONE));
+        for n in [2, 3, 5, 7] {
+            let base: Integer = Integer::from(123_456_789).pow(3);
+            let power: Integer = base.pow(n);
+            assert_eq!(power.root(n), Some(base.clone()));
+            assert_eq!((&power + &Integer::ONE).root(n), None);
+            assert_eq!((&power - &Integer::ONE).root(n), None);
+        }
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for a in values(126) {
+            let text: String = a.to_string();
+            assert_eq!(big(a).to_string(), text);
+            assert_eq!(text.parse::<Integer>().unwrap(), big(a));
+        }
+        let large: Integer = Integer::from(2).pow(100);
+        assert_eq!(large.to_string(), "1267650600228229401496703205376");
+        assert_eq!((-&large).to_string().parse::<Integer>().unwrap(), -large);
+    }
+}
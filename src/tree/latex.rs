@@ -0,0 +1,99 @@
+// Rendering a Node as LaTeX, eg. 3 x^{2} - \frac{1}{x}. The tree is read through print::infix, so subtraction, negation and the
+// parentheses come out the same as the plain printer's. The exceptions are a DIV, which becomes a \frac and so never needs parentheses
+// around its sides, and the exponent of an EXP, which is already grouped by its superscript.
+use super::{Inequality, Node};
+use super::print::{greek, infix, juxtaposes, precedence, Infix, Operator, PRODUCT};
+use super::numeric::Numeric;
+use super::token::Token;
+
+impl<N: Numeric> Node<N> {
+    /// Render the node as LaTeX, eg. `3 x^{2} - \frac{1}{x}`. A DIV is a `\frac`, an EXP a superscript, and a MUL is written as
+    /// `\cdot` only where leaving it out would change how it reads (eg. `2 \cdot 3`, but `2 x`).
+    pub fn to_latex(&self) -> String {
+        let mut latex: String = String::new();
+        render(self, &mut latex);
+        return latex
+    }
+}
+
+impl<N: Numeric> Inequality<N> {
+    /// Render the chain as LaTeX, eg. `0 < x \leq 10`.
+    pub fn to_latex(&self) -> String {
+        let mut latex: String = String::new();
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                latex.push_str(match self.comparisons[i - 1] {
+                    Token::LT => " < ",
+                    Token::LE => " \\leq ",
+                    Token::GT => " > ",
+                    Token::GE => " \\geq ",
+                    ref a => panic!("{:?} isn't a comparison!", a),
+                });
+            }
+            render(term, &mut latex);
+        }
+        return latex
+    }
+}
+
+// A symbol name: single letters as they are, Greek letters as their command, and longer names upright so they don't read as a product.
+fn name(name: &str) -> String {
+    if name.chars().count() == 1 {
+        return name.to_string()
+    }
+    if greek(name).is_some() {
+        return format!("\\{}", name)
+    }
+    return format!("\\mathrm{{{}}}", name)
+}
+
+fn render<N: Numeric>(node: &Node<N>, latex: &mut String) {
+    match infix(node) {
+        Infix::Num(a) => latex.push_str(&a.to_string()),
+        Infix::Var(a) => latex.push_str(&name(a.as_str())),
+        Infix::Neg(a) => {
+            latex.push('-');
+            operand(&a, PRODUCT, latex);
+        }
+        Infix::Binary(Operator::Div, left, right) => {
+            latex.push_str("\\frac{");
+            render(&left, latex);
+            latex.push_str("}{");
+            render(&right, latex);
+            latex.push('}');
+        }
+        Infix::Binary(Operator::Exp, left, right) => {
+            operand(&left, Operator::Exp.bounds().0, latex);
+            latex.push_str("^{");
+            render(&right, latex);
+            latex.push('}');
+        }
+        Infix::Binary(Operator::Mul, left, right) => {
+            let (left_bound, right_bound): (u8, u8) = Operator::Mul.bounds();
+            operand(&left, left_bound, latex);
+            if juxtaposes(&right, right_bound) {
+                latex.push(' ');
+            } else {
+                latex.push_str(" \\cdot ");
+            }
+            operand(&right, right_bound, latex);
+        }
+        Infix::Binary(operator, left, right) => {
+            let (left_bound, right_bound): (u8, u8) = operator.bounds();
+            operand(&left, left_bound, latex);
+            latex.push_str(&format!(" {} ", operator.symbol()));
+            operand(&right, right_bound, latex);
+        }
+    }
+}
+
+// Render a node, wrapped in parentheses if it binds looser than bound.
+fn operand<N: Numeric>(node: &Node<N>, bound: u8, latex: &mut String) {
+    if precedence(node) < bound {
+        latex.push_str("\\left(");
+        render(node, latex);
+        latex.push_str("\\right)");
+    } else {
+        render(node, latex);
+    }
+}
@@ -0,0 +1,104 @@
+// Rendering a Node as presentation MathML, so screen readers can read it out. Like the LaTeX renderer, the tree is read through
+// print::infix, a DIV becomes an <mfrac> and an EXP an <msup>, which group their sides on their own. Every rendered node is a single
+// element (an <mrow> if it has more than one part), so it can always be a child of <mfrac> and <msup>.
+use super::{Inequality, Node};
+use super::print::{greek, infix, juxtaposes, precedence, Infix, Operator, PRODUCT};
+use super::numeric::Numeric;
+use super::token::Token;
+
+const NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+impl<N: Numeric> Node<N> {
+    /// Render the node as a presentation MathML `<math>` element. A DIV is an `<mfrac>`, an EXP an `<msup>`, and a MUL is an invisible
+    /// times (`&#x2062;`) where the factors can sit side by side, or `×` where they can't.
+    pub fn to_mathml(&self) -> String {
+        let mut mathml: String = format!("<math xmlns=\"{}\">", NAMESPACE);
+        render(self, &mut mathml);
+        mathml.push_str("</math>");
+        return mathml
+    }
+}
+
+impl<N: Numeric> Inequality<N> {
+    /// Render the chain as a presentation MathML `<math>` element.
+    pub fn to_mathml(&self) -> String {
+        let mut mathml: String = format!("<math xmlns=\"{}\"><mrow>", NAMESPACE);
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                mathml.push_str(match self.comparisons[i - 1] {
+                    Token::LT => "<mo>&lt;</mo>",
+                    Token::LE => "<mo>&#x2264;</mo>",
+                    Token::GT => "<mo>&gt;</mo>",
+                    Token::GE => "<mo>&#x2265;</mo>",
+                    ref a => panic!("{:?} isn't a comparison!", a),
+                });
+            }
+            render(term, &mut mathml);
+        }
+        mathml.push_str("</mrow></math>");
+        return mathml
+    }
+}
+
+// Escape the characters that can't appear as they are in XML text.
+fn escape(text: &str) -> String {
+    return text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+// A symbol name, with Greek letters written as the letter itself. Names longer than one letter are drawn upright by <mi> on their own.
+fn name(name: &str) -> String {
+    match greek(name) {
+        Some(a) => return format!("<mi>{}</mi>", a),
+        None => return format!("<mi>{}</mi>", escape(name)),
+    }
+}
+
+fn render<N: Numeric>(node: &Node<N>, mathml: &mut String) {
+    match infix(node) {
+        // The sign of a negative NUM is an operator of its own, <mn> only holds the digits
+        Infix::Num(a) if a.is_negative() => mathml.push_str(&format!("<mrow><mo form=\"prefix\">&#x2212;</mo><mn>{}</mn></mrow>", -a.clone())),
+        Infix::Num(a) => mathml.push_str(&format!("<mn>{}</mn>", a)),
+        Infix::Var(a) => mathml.push_str(&name(a.as_str())),
+        Infix::Neg(a) => {
+            mathml.push_str("<mrow><mo form=\"prefix\">&#x2212;</mo>");
+            operand(&a, PRODUCT, mathml);
+            mathml.push_str("</mrow>");
+        }
+        Infix::Binary(Operator::Div, left, right) => {
+            mathml.push_str("<mfrac>");
+            render(&left, mathml);
+            render(&right, mathml);
+            mathml.push_str("</mfrac>");
+        }
+        Infix::Binary(Operator::Exp, left, right) => {
+            mathml.push_str("<msup>");
+            operand(&left, Operator::Exp.bounds().0, mathml);
+            render(&right, mathml);
+            mathml.push_str("</msup>");
+        }
+        Infix::Binary(operator, left, right) => {
+            let (left_bound, right_bound): (u8, u8) = operator.bounds();
+            mathml.push_str("<mrow>");
+            operand(&left, left_bound, mathml);
+            mathml.push_str(match operator {
+                Operator::Add => "<mo>+</mo>",
+                Operator::Sub => "<mo>&#x2212;</mo>",
+                Operator::Mul if juxtaposes(&right, right_bound) => "<mo>&#x2062;</mo>",
+                _ => "<mo>&#xD7;</mo>",
+            });
+            operand(&right, right_bound, mathml);
+            mathml.push_str("</mrow>");
+        }
+    }
+}
+
+// Render a node, wrapped in parentheses if it binds looser than bound.
+fn operand<N: Numeric>(node: &Node<N>, bound: u8, mathml: &mut String) {
+    if precedence(node) < bound {
+        mathml.push_str("<mrow><mo>(</mo>");
+        render(node, mathml);
+        mathml.push_str("<mo>)</mo></mrow>");
+    } else {
+        render(node, mathml);
+    }
+}
@@ -0,0 +1,194 @@
+// The numbers a tree can be built from. Token, Node and Expr hold their NUM values as any Numeric type (Rational unless said otherwise),
+// and the simplifier folds them through the operations below, so the same expression can be simplified exactly with Rational, in
+// hardware floating point with f32 or f64, or in a wider floating point with BigFloat. The type is chosen when an expression is read,
+// eg. parse_as::<f64>, and every expression built from it after that uses the same numbers.
+use super::rational::Rational;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
+use std::str::FromStr;
+
+/// A type of number the simplifier can fold NUM values in.
+pub trait Numeric:
+    Clone
+    + PartialEq
+    + PartialOrd
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + MulAssign
+{
+    /// The number closest to an integer.
+    fn from_integer(value: i64) -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    fn is_negative(&self) -> bool;
+
+    fn is_positive(&self) -> bool {
+        return !self.is_zero() && !self.is_negative()
+    }
+
+    fn is_integer(&self) -> bool;
+
+    fn abs(&self) -> Self {
+        if self.is_negative() {
+            return -self.clone()
+        }
+        return self.clone()
+    }
+
+    /// The number raised to a power, or None if the result can't be stored (eg. 2^(1/2) as a Rational, or (-4)^0.5 as a float), or
+    /// doesn't exist (0 raised to a negative power).
+    ///
+    /// A power is always the principal value, e^(exponent * ln self) with the principal logarithm, whatever the type. So a root of a
+    /// negative number is never the real root, eg. (-8)^(1/3) is 1 + 3^(1/2) * i rather than -2. A type that can't store the principal
+    /// value gives None, and the power is left as it is: every real type for a negative number raised to a power that isn't an integer,
+    /// and `Complex<Rational>` for 1 + 3^(1/2) * i.
+    fn pow(&self, exponent: &Self) -> Option<Self>;
+
+    /// Compare two numbers in the canonical order (see order.rs), which has to be a total order even for floats.
+    fn cmp_num(&self, other: &Self) -> Ordering;
+
+    /// Hash the number, the same for numbers that cmp_num finds equal.
+    fn hash_num<H: Hasher>(&self, state: &mut H);
+
+    /// The number as the nearest f64, for evaluating expressions.
+    fn to_f64(&self) -> f64;
+
+    /// The numerator and denominator the number is printed as, if it is a fraction, eg. 1/3. Floats are always printed as decimals.
+    fn fraction(&self) -> Option<(Self, Self)> {
+        return None
+    }
+
+    /// A power that can't be stored (see pow) as an exact coefficient times a simpler power that is left as it is, eg. 12^(1/2) as
+    /// 2 * 3^(1/2). Gives the coefficient, and the base and exponent of the power, or None if it can't be split up.
+    fn split_root(&self, _exponent: &Self) -> Option<(Self, Self, Self)> {
+        return None
+    }
+
+    /// Whether the number is real, which every number is unless the type is complex.
+    fn is_real(&self) -> bool {
+        return true
+    }
+
+    /// The real and imaginary parts the number is printed as, if it isn't real, eg. 3 + 2 * i.
+    fn complex_parts(&self) -> Option<(Self, Self)> {
+        return None
+    }
+}
+
+impl Numeric for Rational {
+    fn from_integer(value: i64) -> Rational {
+        return Rational::from(value)
+    }
+
+    fn is_zero(&self) -> bool {
+        return Rational::is_zero(self)
+    }
+
+    fn is_negative(&self) -> bool {
+        return Rational::is_negative(self)
+    }
+
+    fn is_integer(&self) -> bool {
+        return Rational::is_integer(self)
+    }
+
+    fn pow(&self, exponent: &Rational) -> Option<Rational> {
+        return Rational::pow(self, exponent)
+    }
+
+    fn cmp_num(&self, other: &Rational) -> Ordering {
+        return self.cmp(other)
+    }
+
+    fn hash_num<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+
+    fn to_f64(&self) -> f64 {
+        return Rational::to_f64(self)
+    }
+
+    fn split_root(&self, exponent: &Rational) -> Option<(Rational, Rational, Rational)> {
+        return Rational::split_root(self, exponent)
+    }
+
+    fn fraction(&self) -> Option<(Rational, Rational)> {
+        if self.is_integer() {
+            return None
+        }
+        return Some((Rational::from(self.numerator().clone()), Rational::from(self.denominator().clone())))
+    }
+}
+
+// f32 and f64 are ordered with -0.0 equal to 0.0, and NaN equal to itself and after every other value.
+macro_rules! float_numeric {
+    ($($float:ident $bits:ident),*) => {
+        $(
+            impl Numeric for $float {
+                fn from_integer(value: i64) -> $float {
+                    return value as $float
+                }
+
+                fn is_zero(&self) -> bool {
+                    return *self == 0.0
+                }
+
+                fn is_negative(&self) -> bool {
+                    return *self < 0.0
+                }
+
+                fn is_integer(&self) -> bool {
+                    return self.fract() == 0.0
+                }
+
+                fn pow(&self, exponent: &$float) -> Option<$float> {
+                    // 0 to a negative power is left as it is like it is with Rational, rather than being folded into an infinity
+                    let power: $float = self.powf(*exponent);
+                    if power.is_nan() || (*self == 0.0 && *exponent < 0.0) {
+                        return None
+                    }
+                    return Some(power)
+                }
+
+                fn cmp_num(&self, other: &$float) -> Ordering {
+                    let normalize = |a: $float| -> $float {
+                        if a.is_nan() {
+                            return $float::NAN
+                        } else if a == 0.0 {
+                            return 0.0
+                        }
+                        return a
+                    };
+                    return normalize(*self).total_cmp(&normalize(*other))
+                }
+
+                fn hash_num<H: Hasher>(&self, state: &mut H) {
+                    let bits: $bits = if self.is_nan() {
+                        $float::NAN.to_bits()
+                    } else if *self == 0.0 {
+                        0
+                    } else {
+                        self.to_bits()
+                    };
+                    bits.hash(state);
+                }
+
+                fn to_f64(&self) -> f64 {
+                    return *self as f64
+                }
+            }
+        )*
+    };
+}
+
+float_numeric!(f32 u32, f64 u64);
@@ -0,0 +1,249 @@
+// The canonical ordering of expressions. The operands of sums and products are put in this order, so that equal expressions are built
+// the same way no matter how they were written (eg. x*y and y*x), and comparing two expressions only has to compare their structure.
+// NUM values come first (smallest to largest), then symbols by name, powers, products, sums and functions. Powers are ordered by their
+// base and then their exponent, and the other operations by their operands from left to right. Node follows the same order as Expr,
+// with a DIV ordered as a product.
+//
+// The same order gives Token, Node and Expr their equality, hashing and ordering, so two expressions are equal when they have the same
+// structure.
+use super::Node;
+use super::expr::Expr;
+use super::numeric::Numeric;
+use super::store::{ExprId, ExprStore, Stored};
+use super::token::Token;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Compare two expressions in the canonical order.
+pub fn cmp_expr<N: Numeric>(left: &Expr<N>, right: &Expr<N>) -> Ordering {
+    let rank = |a: &Expr<N>| -> u8 {
+        match a {
+            Expr::Num(_) => return 0,
+            Expr::Sym(_) => return 1,
+            Expr::Pow(_, _) => return 2,
+            Expr::Mul(_) => return 3,
+            Expr::Add(_) => return 4,
+            Expr::Func(_, _) => return 5,
+        }
+    };
+    match (left, right) {
+        (Expr::Num(a), Expr::Num(b)) => return a.cmp_num(b),
+        (Expr::Sym(a), Expr::Sym(b)) => return a.cmp(b),
+        (Expr::Pow(a, b), Expr::Pow(c, d)) => return cmp_expr(a, c).then_with(|| cmp_expr(b, d)),
+        (Expr::Mul(a), Expr::Mul(b)) | (Expr::Add(a), Expr::Add(b)) => return cmp_all(a, b),
+        (Expr::Func(a, b), Expr::Func(c, d)) => return a.cmp(c).then_with(|| cmp_all(b, d)),
+        _ => return rank(left).cmp(&rank(right)),
+    }
+}
+
+fn cmp_all<N: Numeric>(left: &[Expr<N>], right: &[Expr<N>]) -> Ordering {
+    for (a, b) in left.iter().zip(right.iter()) {
+        let c: Ordering = cmp_expr(a, b);
+        if c != Ordering::Equal {
+            return c
+        }
+    }
+    return left.len().cmp(&right.len())
+}
+
+/// Compare two expressions in a store in the canonical order, the same as cmp_expr. Equal expressions share an id, so they are found
+/// equal without looking at them.
+pub fn cmp_stored<N: Numeric>(store: &ExprStore<N>, left: ExprId, right: ExprId) -> Ordering {
+    if left == right {
+        return Ordering::Equal
+    }
+    let rank = |a: &Stored<N>| -> u8 {
+        match a {
+            Stored::Num(_) => return 0,
+            Stored::Sym(_) => return 1,
+            Stored::Pow(_, _) => return 2,
+            Stored::Mul(_) => return 3,
+            Stored::Add(_) => return 4,
+            Stored::Func(_, _) => return 5,
+        }
+    };
+    let all = |left: &[ExprId], right: &[ExprId]| -> Ordering {
+        for (a, b) in left.iter().zip(right.iter()) {
+            let c: Ordering = cmp_stored(store, *a, *b);
+            if c != Ordering::Equal {
+                return c
+            }
+        }
+        return left.len().cmp(&right.len())
+    };
+    match (store.get(left), store.get(right)) {
+        (Stored::Num(a), Stored::Num(b)) => return a.cmp_num(b),
+        (Stored::Sym(a), Stored::Sym(b)) => return a.cmp(b),
+        (Stored::Pow(a, b), Stored::Pow(c, d)) => return cmp_stored(store, *a, *c).then_with(|| cmp_stored(store, *b, *d)),
+        (Stored::Mul(a), Stored::Mul(b)) | (Stored::Add(a), Stored::Add(b)) => return all(a, b),
+        (Stored::Func(a, b), Stored::Func(c, d)) => return a.cmp(c).then_with(|| all(b, d)),
+        (a, b) => return rank(a).cmp(&rank(b)),
+    }
+}
+
+// The rank of each Token, the operations are ranked the same as the kind of Expr they become.
+fn token_rank<N: Numeric>(a: &Token<N>) -> u8 {
+    match a {
+        Token::NUM(_) => return 0,
+        Token::VAR(_) => return 1,
+        Token::EXP => return 2,
+        Token::MUL | Token::DIV => return 3,
+        Token::ADD => return 4,
+        Token::LGROUP => return 5,
+        Token::RGROUP => return 6,
+        Token::LT => return 7,
+        Token::LE => return 8,
+        Token::GT => return 9,
+        Token::GE => return 10,
+    }
+}
+
+/// Compare two tokens in the canonical order, MUL comes before DIV.
+pub fn cmp_token<N: Numeric>(left: &Token<N>, right: &Token<N>) -> Ordering {
+    match (left, right) {
+        (Token::NUM(a), Token::NUM(b)) => return a.cmp_num(b),
+        (Token::VAR(a), Token::VAR(b)) => return a.cmp(b),
+        (a, b) => return token_rank(a).cmp(&token_rank(b)).then_with(|| matches!(a, Token::DIV).cmp(&matches!(b, Token::DIV))),
+    }
+}
+
+// A step of comparing two nodes: compare two sides, or an ordering already found that only counts if everything before it is equal.
+enum Compare<'a, N> {
+    Sides(Option<&'a Node<N>>, Option<&'a Node<N>>),
+    Then(Ordering),
+}
+
+/// Compare two nodes in the canonical order.
+pub fn cmp_node<N: Numeric>(left: &Node<N>, right: &Node<N>) -> Ordering {
+    // A long sum or product is a chain as deep as it is long, so the sides are compared with a stack of the steps still to go rather
+    // than recursively. The left sides are compared first, then the right sides, and then whether the operations are a DIV.
+    let mut steps: Vec<Compare<N>> = vec![Compare::Sides(Some(left), Some(right))];
+    while let Some(step) = steps.pop() {
+        let (left, right): (&Node<N>, &Node<N>) = match step {
+            Compare::Sides(Some(a), Some(b)) => (a, b),
+            Compare::Sides(a, b) if a.is_some() != b.is_some() => return a.is_some().cmp(&b.is_some()),
+            Compare::Sides(_, _) => continue,
+            Compare::Then(a) if a != Ordering::Equal => return a,
+            Compare::Then(_) => continue,
+        };
+        let ordering: Ordering = match (&left.data_type, &right.data_type) {
+            (Token::NUM(a), Token::NUM(b)) => a.cmp_num(b),
+            (Token::VAR(a), Token::VAR(b)) => a.cmp(b),
+            (a, b) if token_rank(a) == token_rank(b) => {
+                steps.push(Compare::Then(matches!(a, Token::DIV).cmp(&matches!(b, Token::DIV))));
+                steps.push(Compare::Sides(left.right.as_deref(), right.right.as_deref()));
+                steps.push(Compare::Sides(left.left.as_deref(), right.left.as_deref()));
+                continue;
+            }
+            (a, b) => token_rank(a).cmp(&token_rank(b)),
+        };
+        if ordering != Ordering::Equal {
+            return ordering
+        }
+    }
+    return Ordering::Equal
+}
+
+impl<N: Numeric> PartialEq for Token<N> {
+    fn eq(&self, other: &Token<N>) -> bool {
+        return cmp_token(self, other) == Ordering::Equal
+    }
+}
+
+impl<N: Numeric> Eq for Token<N> {}
+
+impl<N: Numeric> PartialOrd for Token<N> {
+    fn partial_cmp(&self, other: &Token<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Numeric> Ord for Token<N> {
+    fn cmp(&self, other: &Token<N>) -> Ordering {
+        return cmp_token(self, other)
+    }
+}
+
+impl<N: Numeric> Hash for Token<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Token::NUM(a) => a.hash_num(state),
+            Token::VAR(a) => a.hash(state),
+            _ => {}
+        }
+    }
+}
+
+impl<N: Numeric> PartialEq for Node<N> {
+    fn eq(&self, other: &Node<N>) -> bool {
+        return cmp_node(self, other) == Ordering::Equal
+    }
+}
+
+impl<N: Numeric> Eq for Node<N> {}
+
+impl<N: Numeric> PartialOrd for Node<N> {
+    fn partial_cmp(&self, other: &Node<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Numeric> Ord for Node<N> {
+    fn cmp(&self, other: &Node<N>) -> Ordering {
+        return cmp_node(self, other)
+    }
+}
+
+// Hashed node by node, parents before their sides, with a stack of the nodes still to go for the same reason as cmp_node.
+impl<N: Numeric> Hash for Node<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut pending: Vec<&Node<N>> = vec![self];
+        while let Some(a) = pending.pop() {
+            a.data_type.hash(state);
+            a.left.is_some().hash(state);
+            a.right.is_some().hash(state);
+            pending.extend(a.right.as_deref());
+            pending.extend(a.left.as_deref());
+        }
+    }
+}
+
+impl<N: Numeric> PartialEq for Expr<N> {
+    fn eq(&self, other: &Expr<N>) -> bool {
+        return cmp_expr(self, other) == Ordering::Equal
+    }
+}
+
+impl<N: Numeric> Eq for Expr<N> {}
+
+impl<N: Numeric> PartialOrd for Expr<N> {
+    fn partial_cmp(&self, other: &Expr<N>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Numeric> Ord for Expr<N> {
+    fn cmp(&self, other: &Expr<N>) -> Ordering {
+        return cmp_expr(self, other)
+    }
+}
+
+impl<N: Numeric> Hash for Expr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Expr::Num(a) => a.hash_num(state),
+            Expr::Sym(a) => a.hash(state),
+            Expr::Add(a) | Expr::Mul(a) => a.hash(state),
+            Expr::Pow(a, b) => {
+                a.hash(state);
+                b.hash(state);
+            }
+            Expr::Func(a, b) => {
+                a.hash(state);
+                b.hash(state);
+            }
+        }
+    }
+}
@@ -0,0 +1,298 @@
+// Printing a Node over several lines, with stacked fractions, raised exponents and radical signs, eg.
+//
+//        2    1
+//   3 * x  - ---
+//             x
+//
+// Every part of the expression is laid out as a Block of lines, and blocks are put side by side lined up on their baselines (the line
+// the operators sit on). The tree is read through print::infix, like the other printers. The Unicode mode draws with box-drawing
+// characters instead of ASCII, and writes NUM exponents of a single line as superscripts, eg. x².
+use super::{Inequality, Node};
+use super::print::{infix, precedence, Infix, Operator, PRODUCT};
+use super::numeric::Numeric;
+use super::token::Token;
+
+// The characters to draw with.
+struct Charset {
+    times: &'static str,
+    bar: char,
+    radical: (char, char),
+    overline: char,
+    parens: [(char, char, char); 2],
+    comparisons: [&'static str; 4],
+    superscripts: bool,
+}
+
+const ASCII: Charset = Charset {
+    times: " * ",
+    bar: '-',
+    radical: ('\\', '/'),
+    overline: '_',
+    parens: [('/', '|', '\\'), ('\\', '|', '/')],
+    comparisons: [" < ", " <= ", " > ", " >= "],
+    superscripts: false,
+};
+
+const UNICODE: Charset = Charset {
+    times: "⋅",
+    bar: '─',
+    radical: ('╲', '╱'),
+    overline: '_',
+    parens: [('⎛', '⎜', '⎝'), ('⎞', '⎟', '⎠')],
+    comparisons: [" < ", " ≤ ", " > ", " ≥ "],
+    superscripts: true,
+};
+
+impl<N: Numeric> Node<N> {
+    /// Print the node over several lines, with stacked fractions, raised exponents and radical signs. With `unicode` it is drawn with
+    /// box-drawing characters and superscripts, otherwise with ASCII only.
+    pub fn to_pretty(&self, unicode: bool) -> String {
+        return layout(self, charset(unicode)).to_string()
+    }
+}
+
+impl<N: Numeric> Inequality<N> {
+    /// Print the chain over several lines, see Node::to_pretty.
+    pub fn to_pretty(&self, unicode: bool) -> String {
+        let charset: &Charset = charset(unicode);
+        let mut blocks: Vec<Block> = Vec::new();
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                let comparison: &str = match self.comparisons[i - 1] {
+                    Token::LT => charset.comparisons[0],
+                    Token::LE => charset.comparisons[1],
+                    Token::GT => charset.comparisons[2],
+                    Token::GE => charset.comparisons[3],
+                    ref a => panic!("{:?} isn't a comparison!", a),
+                };
+                blocks.push(Block::text(comparison));
+            }
+            blocks.push(layout(term, charset));
+        }
+        return Block::beside(blocks).to_string()
+    }
+}
+
+fn charset(unicode: bool) -> &'static Charset {
+    if unicode {
+        return &UNICODE
+    }
+    return &ASCII
+}
+
+// A rectangle of text. Every line is padded to the same width, and baseline is the index of the line the block lines up on.
+struct Block {
+    lines: Vec<String>,
+    width: usize,
+    baseline: usize,
+}
+
+impl Block {
+    fn text(text: &str) -> Block {
+        return Block {
+            lines: vec![text.to_string()],
+            width: text.chars().count(),
+            baseline: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        return self.lines.len()
+    }
+
+    // Blocks side by side, lined up on their baselines.
+    fn beside(blocks: Vec<Block>) -> Block {
+        let above: usize = blocks.iter().map(|a| a.baseline).max().unwrap_or(0);
+        let below: usize = blocks.iter().map(|a| a.height() - a.baseline).max().unwrap_or(1);
+        let mut lines: Vec<String> = vec![String::new(); above + below];
+        let mut width: usize = 0;
+        for block in blocks {
+            let top: usize = above - block.baseline;
+            for (i, line) in lines.iter_mut().enumerate() {
+                if i >= top && i < top + block.height() {
+                    line.push_str(&block.lines[i - top]);
+                } else {
+                    line.push_str(&" ".repeat(block.width));
+                }
+            }
+            width += block.width;
+        }
+        return Block {
+            lines: lines,
+            width: width,
+            baseline: above,
+        }
+    }
+
+    // The block with the given number of spaces on either side.
+    fn pad(mut self, left: usize, right: usize) -> Block {
+        for line in self.lines.iter_mut() {
+            *line = format!("{}{}{}", " ".repeat(left), line, " ".repeat(right));
+        }
+        self.width += left + right;
+        return self
+    }
+
+    // One block over the other with a bar between them, each centred on the bar.
+    fn fraction(numerator: Block, denominator: Block, bar: char) -> Block {
+        let width: usize = numerator.width.max(denominator.width) + 2;
+        let centre = |a: Block| -> Block {
+            let left: usize = (width - a.width) / 2;
+            let right: usize = width - a.width - left;
+            return a.pad(left, right)
+        };
+        let baseline: usize = numerator.height();
+        let mut lines: Vec<String> = centre(numerator).lines;
+        lines.push(bar.to_string().repeat(width));
+        lines.extend(centre(denominator).lines);
+        return Block {
+            lines: lines,
+            width: width,
+            baseline: baseline,
+        }
+    }
+
+    // The exponent raised above the end of the base.
+    fn power(base: Block, exponent: Block) -> Block {
+        let mut lines: Vec<String> = Vec::new();
+        for line in exponent.lines.iter() {
+            lines.push(format!("{}{}", " ".repeat(base.width), line));
+        }
+        for line in base.lines.iter() {
+            lines.push(format!("{}{}", line, " ".repeat(exponent.width)));
+        }
+        return Block {
+            lines: lines,
+            width: base.width + exponent.width,
+            baseline: exponent.height() + base.baseline,
+        }
+    }
+
+    // The block in parentheses as tall as it is.
+    fn parenthesize(self, charset: &Charset) -> Block {
+        let side = |(top, middle, bottom): (char, char, char), plain: char, i: usize, height: usize| -> char {
+            if height == 1 {
+                return plain
+            } else if i == 0 {
+                return top
+            } else if i == height - 1 {
+                return bottom
+            }
+            return middle
+        };
+        let height: usize = self.height();
+        let lines: Vec<String> = self.lines.iter().enumerate().map(|(i, line)| {
+            return format!("{}{}{}", side(charset.parens[0], '(', i, height), line, side(charset.parens[1], ')', i, height))
+        }).collect();
+        return Block {
+            lines: lines,
+            width: self.width + 2,
+            baseline: self.baseline,
+        }
+    }
+
+    // The block under a radical sign, which slopes up along its left side and has a line over it.
+    fn radical(self, charset: &Charset) -> Block {
+        let height: usize = self.height();
+        let sign: usize = height + 1;
+        let mut lines: Vec<String> = vec![format!("{}{}", " ".repeat(sign), charset.overline.to_string().repeat(self.width + 1))];
+        for (i, line) in self.lines.iter().enumerate() {
+            let mut left: Vec<char> = vec![' '; sign];
+            if i == height - 1 {
+                left[0] = charset.radical.0;
+                left[1] = charset.radical.1;
+            } else {
+                left[height - i] = charset.radical.1;
+            }
+            lines.push(format!("{} {}", left.into_iter().collect::<String>(), line));
+        }
+        return Block {
+            lines: lines,
+            width: sign + 1 + self.width,
+            baseline: self.baseline + 1,
+        }
+    }
+}
+
+// Lines are joined without the spaces padding their right ends.
+impl std::fmt::Display for Block {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let lines: Vec<&str> = self.lines.iter().map(|a| a.trim_end()).collect();
+        return f.write_str(&lines.join("\n"))
+    }
+}
+
+// The characters of a NUM written as superscripts, if they all have one.
+fn superscript<N: Numeric>(num: &N) -> Option<String> {
+    let mut raised: String = String::new();
+    for a in num.to_string().chars() {
+        raised.push(match a {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            '-' => '⁻',
+            _ => return None,
+        });
+    }
+    return Some(raised)
+}
+
+// Whether an exponent is a half, so the power is a square root.
+fn is_half<N: Numeric>(exponent: &Node<N>) -> bool {
+    match infix(exponent) {
+        // A NUM of 1/2 reads as 1 / 2 too
+        Infix::Binary(Operator::Div, left, right) => {
+            return left.data_type == Token::NUM(N::from_integer(1)) && right.data_type == Token::NUM(N::from_integer(2))
+        }
+        _ => return false,
+    }
+}
+
+fn layout<N: Numeric>(node: &Node<N>, charset: &Charset) -> Block {
+    match infix(node) {
+        Infix::Num(a) => return Block::text(&a.to_string()),
+        Infix::Var(a) => return Block::text(a.as_str()),
+        Infix::Neg(a) => {
+            // A minus sign right before a fraction would run into its bar, so it is spaced apart from anything taller than a line
+            let negated: Block = operand(&a, PRODUCT, charset);
+            let sign: &str = if negated.height() > 1 { "- " } else { "-" };
+            return Block::beside(vec![Block::text(sign), negated])
+        }
+        Infix::Binary(Operator::Div, left, right) => return Block::fraction(layout(&left, charset), layout(&right, charset), charset.bar),
+        Infix::Binary(Operator::Exp, left, right) => {
+            if is_half(&right) {
+                return layout(&left, charset).radical(charset)
+            }
+            let base: Block = operand(&left, Operator::Exp.bounds().0, charset);
+            if let (true, 1, Token::NUM(a)) = (charset.superscripts, base.height(), &right.data_type) {
+                if let Some(raised) = superscript(a) {
+                    return Block::beside(vec![base, Block::text(&raised)])
+                }
+            }
+            return Block::power(base, layout(&right, charset))
+        }
+        Infix::Binary(operator, left, right) => {
+            let (left_bound, right_bound): (u8, u8) = operator.bounds();
+            let symbol: String = match operator {
+                Operator::Mul => charset.times.to_string(),
+                a => format!(" {} ", a.symbol()),
+            };
+            return Block::beside(vec![operand(&left, left_bound, charset), Block::text(&symbol), operand(&right, right_bound, charset)])
+        }
+    }
+}
+
+// Lay out a node, wrapped in parentheses if it binds looser than bound.
+fn operand<N: Numeric>(node: &Node<N>, bound: u8, charset: &Charset) -> Block {
+    if precedence(node) < bound {
+        return layout(node, charset).parenthesize(charset)
+    }
+    return layout(node, charset)
+}
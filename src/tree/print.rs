@@ -0,0 +1,265 @@
+// Printing a Node in infix notation, eg. x ^ 2 + 3 * x - 1. The tree has no subtraction or negation of its own (they are read in as ADD
+// and MUL by -1), so infix finds them again, and the precedences and bounds below decide where parentheses are needed. Every printer
+// reads the tree through infix, so they all agree on where the parentheses go.
+use super::{Inequality, Node};
+use super::symbol::Symbol;
+use super::numeric::Numeric;
+use super::token::Token;
+use std::borrow::Cow;
+use std::fmt;
+
+// Precedences, from loosest to tightest. A negation binds tighter than a sum but looser than a product, so -(x + 1) needs parentheses
+// and -2 * x doesn't.
+pub(crate) const SUM: u8 = 1;
+pub(crate) const NEGATION: u8 = 2;
+pub(crate) const PRODUCT: u8 = 3;
+pub(crate) const POWER: u8 = 4;
+pub(crate) const ATOM: u8 = 5;
+
+#[derive(Debug)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Exp,
+}
+
+impl Operator {
+    pub(crate) fn precedence(&self) -> u8 {
+        match self {
+            Operator::Add | Operator::Sub => return SUM,
+            Operator::Mul | Operator::Div => return PRODUCT,
+            Operator::Exp => return POWER,
+        }
+    }
+
+    // The lowest precedence the left and right side can have without being wrapped in parentheses. Sums and products are associative,
+    // so a + (b + c) is printed as a + b + c, but subtraction and division aren't. A negation can start a product (-2 * x is -(2 * x))
+    // but not follow an operator. Both sides of an EXP are wrapped unless they are a single value, so x ^ (a ^ b) can't be misread.
+    pub(crate) fn bounds(&self) -> (u8, u8) {
+        match self {
+            Operator::Add => return (SUM, SUM),
+            Operator::Sub => return (SUM, PRODUCT),
+            Operator::Mul => return (NEGATION, PRODUCT),
+            Operator::Div => return (NEGATION, POWER),
+            Operator::Exp => return (ATOM, ATOM),
+        }
+    }
+
+    pub(crate) fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Add => return "+",
+            Operator::Sub => return "-",
+            Operator::Mul => return "*",
+            Operator::Div => return "/",
+            Operator::Exp => return "^",
+        }
+    }
+}
+
+/// How a node reads in infix notation.
+pub(crate) enum Infix<'a, N: Numeric> {
+    Num(&'a N),
+    Var(Symbol),
+    Neg(Cow<'a, Node<N>>),
+    Binary(Operator, Cow<'a, Node<N>>, Cow<'a, Node<N>>),
+}
+
+// The side of an operator, which it can't be without.
+fn side<'a, N: Numeric>(side: &'a Option<Box<Node<N>>>, data_type: &Token<N>) -> &'a Node<N> {
+    match side {
+        Some(a) => return a,
+        None => panic!("The operator {:?} is missing a value!", data_type),
+    }
+}
+
+// The node without its leading minus sign, if it has one, eg. 3 for -3, x for -1 * x, and 2 * x / y for -2 * x / y.
+fn negated<N: Numeric>(node: &Node<N>) -> Option<Cow<'_, Node<N>>> {
+    match &node.data_type {
+        Token::NUM(a) if a.is_negative() => return Some(Cow::Owned(Node::num(-a.clone()))),
+        Token::MUL | Token::DIV => {
+            let left: Cow<Node<N>> = negated(side(&node.left, &node.data_type))?;
+            let right: &Node<N> = side(&node.right, &node.data_type);
+            if matches!(node.data_type, Token::MUL) && matches!(&left.data_type, Token::NUM(a) if *a == N::from_integer(1)) {
+                return Some(Cow::Borrowed(right))
+            }
+            return Some(Cow::Owned(Node {
+                data_type: node.data_type.clone(),
+                left: Some(Box::new(left.into_owned())),
+                right: Some(Box::new(right.clone())),
+            }))
+        }
+        _ => return None,
+    }
+}
+
+/// Read a node as infix notation, finding subtraction (a + -1 * b is a - b) and negation (-1 * x is -x). A NUM that is a fraction reads
+/// as the division of its numerator by its denominator, eg. 1/3 is 1 / 3 and -1/3 is -(1 / 3), and a complex NUM as the sum of its real
+/// part and its imaginary part times i, eg. 3+2i is 3 + 2 * i and 3-i is 3 - i, so Infix::Num is always a real integer or float.
+pub(crate) fn infix<N: Numeric>(node: &Node<N>) -> Infix<'_, N> {
+    let operator: Operator = match &node.data_type {
+        Token::NUM(a) if a.complex_parts().is_some() => {
+            let (re, im): (N, N) = a.complex_parts().unwrap();
+            let unit: Node<N> = Node::var("i");
+            if re.is_zero() && im == N::from_integer(1) {
+                return Infix::Var(Symbol::new("i"))
+            } else if re.is_zero() && im == N::from_integer(-1) {
+                return Infix::Neg(Cow::Owned(unit))
+            } else if re.is_zero() {
+                return Infix::Binary(Operator::Mul, Cow::Owned(Node::num(im)), Cow::Owned(unit))
+            }
+            let operator: Operator = if im.is_negative() { Operator::Sub } else { Operator::Add };
+            let im: N = im.abs();
+            let imaginary: Node<N> = if im == N::from_integer(1) { unit } else { Node::operation(Token::MUL, Node::num(im), unit) };
+            return Infix::Binary(operator, Cow::Owned(Node::num(re)), Cow::Owned(imaginary))
+        }
+        Token::NUM(a) => match a.fraction() {
+            Some(_) if a.is_negative() => return Infix::Neg(Cow::Owned(Node::num(-a.clone()))),
+            Some((numerator, denominator)) => {
+                return Infix::Binary(Operator::Div, Cow::Owned(Node::num(numerator)), Cow::Owned(Node::num(denominator)))
+            }
+            None => return Infix::Num(a),
+        },
+        Token::VAR(a) => return Infix::Var(*a),
+        Token::ADD => Operator::Add,
+        Token::MUL => Operator::Mul,
+        Token::DIV => Operator::Div,
+        Token::EXP => Operator::Exp,
+        a => panic!("{:?} can't be part of an expression!", a),
+    };
+    let left: &Node<N> = side(&node.left, &node.data_type);
+    let right: &Node<N> = side(&node.right, &node.data_type);
+    match operator {
+        Operator::Add => {
+            if let Some(a) = negated(right) {
+                return Infix::Binary(Operator::Sub, Cow::Borrowed(left), a)
+            }
+        }
+        Operator::Mul if matches!(&left.data_type, Token::NUM(a) if *a == N::from_integer(-1)) => return Infix::Neg(Cow::Borrowed(right)),
+        _ => {}
+    }
+    return Infix::Binary(operator, Cow::Borrowed(left), Cow::Borrowed(right))
+}
+
+/// The precedence of a node, as it would be printed.
+pub(crate) fn precedence<N: Numeric>(node: &Node<N>) -> u8 {
+    match infix(node) {
+        Infix::Num(a) if a.is_negative() => return NEGATION,
+        Infix::Num(_) | Infix::Var(_) => return ATOM,
+        Infix::Neg(_) => return NEGATION,
+        Infix::Binary(operator, _, _) => return operator.precedence(),
+    }
+}
+
+/// Whether a factor can follow another one without a multiplication sign and still read as a product, which is when it starts with a
+/// letter or a parenthesis, eg. 2 x or x (y + 1), but not 2 3. This is for printers that write DIV as a fraction, which needs the sign too.
+pub(crate) fn juxtaposes<N: Numeric>(factor: &Node<N>, bound: u8) -> bool {
+    if precedence(factor) < bound {
+        return true
+    }
+    match infix(factor) {
+        Infix::Var(_) => return true,
+        Infix::Binary(Operator::Mul, left, _) => return juxtaposes(&left, Operator::Mul.bounds().0),
+        Infix::Binary(Operator::Exp, left, _) => return juxtaposes(&left, Operator::Exp.bounds().0),
+        _ => return false,
+    }
+}
+
+// Symbol names that are Greek letters, with the letter itself.
+const GREEK: [(&str, char); 40] = [
+    ("alpha", 'α'), ("beta", 'β'), ("gamma", 'γ'), ("delta", 'δ'), ("epsilon", 'ϵ'), ("zeta", 'ζ'), ("eta", 'η'), ("theta", 'θ'),
+    ("iota", 'ι'), ("kappa", 'κ'), ("lambda", 'λ'), ("mu", 'μ'), ("nu", 'ν'), ("xi", 'ξ'), ("pi", 'π'), ("rho", 'ρ'), ("sigma", 'σ'),
+    ("tau", 'τ'), ("upsilon", 'υ'), ("phi", 'ϕ'), ("chi", 'χ'), ("psi", 'ψ'), ("omega", 'ω'), ("varepsilon", 'ε'), ("vartheta", 'ϑ'),
+    ("varpi", 'ϖ'), ("varrho", 'ϱ'), ("varsigma", 'ς'), ("varphi", 'φ'), ("Gamma", 'Γ'), ("Delta", 'Δ'), ("Theta", 'Θ'), ("Lambda", 'Λ'),
+    ("Xi", 'Ξ'), ("Pi", 'Π'), ("Sigma", 'Σ'), ("Upsilon", 'ϒ'), ("Phi", 'Φ'), ("Psi", 'Ψ'), ("Omega", 'Ω'),
+];
+
+/// The Greek letter a symbol name spells out, if it is one.
+pub(crate) fn greek(name: &str) -> Option<char> {
+    return GREEK.iter().find(|a| a.0 == name).map(|a| a.1)
+}
+
+// A piece of printing a node: a node, wrapped in parentheses if it binds looser than the bound, an operator between two nodes, or the
+// parenthesis closing a node.
+enum Piece<'a, N: Numeric> {
+    Node(Cow<'a, Node<N>>, u8),
+    Operator(Operator),
+    Close,
+}
+
+// Write a value, or push the pieces a node is printed as, with keep taking its sides out of it when they can't be borrowed past it.
+fn push_infix<'a, 'b, N: Numeric>(f: &mut fmt::Formatter, infix: Infix<'b, N>, pieces: &mut Vec<Piece<'a, N>>, keep: fn(Cow<'b, Node<N>>) -> Cow<'a, Node<N>>) -> fmt::Result {
+    match infix {
+        Infix::Num(a) => return write!(f, "{}", a),
+        Infix::Var(a) => return write!(f, "{}", a),
+        Infix::Neg(a) => {
+            pieces.push(Piece::Node(keep(a), PRODUCT));
+            return f.write_str("-")
+        }
+        Infix::Binary(operator, left, right) => {
+            let (left_bound, right_bound): (u8, u8) = operator.bounds();
+            pieces.push(Piece::Node(keep(right), right_bound));
+            pieces.push(Piece::Operator(operator));
+            pieces.push(Piece::Node(keep(left), left_bound));
+            return Ok(())
+        }
+    }
+}
+
+/// Prints the node in infix notation with as few parentheses as possible, eg. `x ^ 2 + 3 * x - 1`.
+impl<N: Numeric> fmt::Display for Node<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // A long sum or product is a chain as deep as it is long (a + b + c is (a + b) + c), so the node is printed with a stack of the
+        // pieces still to go rather than recursively. The sides of a node made while reading another one (eg. 2 * x, read from -2 * x)
+        // are taken out of it, since it is dropped once its pieces are pushed.
+        let mut pieces: Vec<Piece<N>> = vec![Piece::Node(Cow::Borrowed(self), SUM)];
+        while let Some(piece) = pieces.pop() {
+            let (node, bound): (Cow<Node<N>>, u8) = match piece {
+                Piece::Node(a, b) => (a, b),
+                Piece::Operator(a) => {
+                    write!(f, " {} ", a.symbol())?;
+                    continue;
+                }
+                Piece::Close => {
+                    f.write_str(")")?;
+                    continue;
+                }
+            };
+            if precedence(&node) < bound {
+                f.write_str("(")?;
+                pieces.push(Piece::Close);
+            }
+            match node {
+                Cow::Borrowed(a) => push_infix(f, infix(a), &mut pieces, |a| a)?,
+                Cow::Owned(a) => push_infix(f, infix(&a), &mut pieces, |a| Cow::Owned(a.into_owned()))?,
+            }
+        }
+        return Ok(())
+    }
+}
+
+/// The symbol of a comparison, eg. `<=` for LE.
+pub(crate) fn comparison_symbol<N: Numeric>(comparison: &Token<N>) -> &'static str {
+    match comparison {
+        Token::LT => return "<",
+        Token::LE => return "<=",
+        Token::GT => return ">",
+        Token::GE => return ">=",
+        a => panic!("{:?} isn't a comparison!", a),
+    }
+}
+
+/// Prints the chain with each term in infix notation, eg. `0 < x <= 10`.
+impl<N: Numeric> fmt::Display for Inequality<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                write!(f, " {} ", comparison_symbol(&self.comparisons[i - 1]))?;
+            }
+            write!(f, "{}", term)?;
+        }
+        return Ok(())
+    }
+}
@@ -0,0 +1,402 @@
+// Exact rational numbers, the numbers of NUM tokens, with an Integer numerator and denominator of any size. A Rational is always kept in
+// lowest terms with a positive denominator, so two equal numbers have the same numerator and denominator, and can be compared, hashed
+// and matched on like integers. Arithmetic never rounds or overflows, eg. 1/3 * 3 is exactly 1, and floats only come out of an explicit
+// conversion such as to_f32.
+use super::integer::Integer;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
+use std::str::FromStr;
+
+// The most bits a power is allowed to have in its numerator or denominator. Anything larger (eg. 2^(10^9)) is left as a power instead.
+const MAX_POWER_BITS: u64 = 1 << 20;
+
+// The largest exponent a decimal may have, eg. 1e100000.
+const MAX_DECIMAL_EXPONENT: i64 = 100_000;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: Integer,
+    denominator: Integer,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numerator: Integer::ZERO, denominator: Integer::ONE };
+    pub const ONE: Rational = Rational { numerator: Integer::ONE, denominator: Integer::ONE };
+    pub const NEG_ONE: Rational = Rational { numerator: Integer::NEG_ONE, denominator: Integer::ONE };
+
+    /// The fraction numerator / denominator, in lowest terms.
+    pub fn new<N: Into<Integer>, D: Into<Integer>>(numerator: N, denominator: D) -> Rational {
+        return Rational::reduce(numerator.into(), denominator.into())
+    }
+
+    // Put a fraction in lowest terms with a positive denominator.
+    fn reduce(numerator: Integer, denominator: Integer) -> Rational {
+        if denominator.is_zero() {
+            panic!("Can't divide by 0!");
+        }
+        let divisor: Integer = numerator.gcd(&denominator);
+        let divisor: Integer = if denominator.is_negative() { -divisor } else { divisor };
+        return Rational {
+            numerator: &numerator / &divisor,
+            denominator: &denominator / &divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> &Integer {
+        return &self.numerator
+    }
+
+    pub fn denominator(&self) -> &Integer {
+        return &self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.numerator.is_zero()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        return self.numerator.is_positive()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        return self.numerator.is_negative()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        return self.denominator == Integer::ONE
+    }
+
+    pub fn abs(&self) -> Rational {
+        return Rational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// 1 divided by the number.
+    pub fn recip(&self) -> Rational {
+        return Rational::reduce(self.denominator.clone(), self.numerator.clone())
+    }
+
+    /// The number raised to an integer power.
+    pub fn powi(&self, exponent: i32) -> Rational {
+        let base: Rational = if exponent < 0 { self.recip() } else { self.clone() };
+        return Rational {
+            numerator: base.numerator.pow(exponent.unsigned_abs()),
+            denominator: base.denominator.pow(exponent.unsigned_abs()),
+        }
+    }
+
+    /// The number raised to a rational power, if the result is rational, eg. 4^(3/2) is 8 but 2^(1/2) is None. Powers too large to
+    /// work out are None as well, and so is 0 raised to a negative power. A negative number raised to a power that isn't an integer is
+    /// None too, since its principal root isn't real, eg. (-8)^(1/3) is 1 + 3^(1/2) * i rather than -2.
+    pub fn pow(&self, exponent: &Rational) -> Option<Rational> {
+        if (self.is_zero() && exponent.is_negative()) || (self.is_negative() && !exponent.is_integer()) {
+            return None
+        }
+        let power: i32 = i32::try_from(exponent.numerator.to_i64()?).ok()?;
+        let bits: u64 = self.numerator.bits().max(self.denominator.bits());
+        if bits.saturating_mul(power.unsigned_abs() as u64) > MAX_POWER_BITS {
+            return None
+        }
+        if exponent.is_integer() {
+            return Some(self.powi(power))
+        }
+        let n: u32 = u32::try_from(exponent.denominator.to_i64()?).ok()?;
+        let root: Rational = Rational {
+            numerator: self.numerator.root(n)?,
+            denominator: self.denominator.root(n)?,
+        };
+        return Some(root.powi(power))
+    }
+
+    /// A positive number raised to a rational power that isn't rational, as a rational coefficient times the smallest power left,
+    /// eg. 12^(1/2) is 2 * 3^(1/2), 2^(3/2) is 2 * 2^(1/2) and (1/2)^(1/2) is 1/2 * 2^(1/2). Gives the coefficient, and the base and
+    /// exponent of the power, or None if the power is already as small as it can be.
+    pub fn split_root(&self, exponent: &Rational) -> Option<(Rational, Rational, Rational)> {
+        if !self.is_positive() || exponent.is_integer() {
+            return None
+        }
+        let n: u32 = u32::try_from(exponent.denominator.to_i64()?).ok()?;
+        // The whole powers, leaving an exponent between 0 and 1
+        let mut whole: Rational = Rational::from(exponent.numerator.div_rem(&exponent.denominator).0);
+        if &whole > exponent {
+            whole = whole - Rational::ONE;
+        }
+        let fraction: Rational = exponent - &whole;
+        let power: i32 = i32::try_from(fraction.numerator.to_i64()?).ok()?;
+        // a/b is a * b^(n-1) / b^n, so the denominator comes out of the root
+        if self.denominator.bits().saturating_mul(n as u64) > MAX_POWER_BITS {
+            return None
+        }
+        let (root, kept): (Integer, Integer) = (&self.numerator * &self.denominator.pow(n - 1)).split_root(n);
+        let coefficient: Rational = self.pow(&whole)? * Rational::new(root, self.denominator.clone()).powi(power);
+        let base: Rational = Rational::from(kept);
+        if coefficient == Rational::ONE && &base == self && &fraction == exponent {
+            return None
+        }
+        return Some((coefficient, base, fraction))
+    }
+
+    pub fn to_f32(&self) -> f32 {
+        return self.to_f64() as f32
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        // Drop the low bits of numbers too large for an f64, which only changes the quotient past the precision of an f64
+        let excess: u64 = self.numerator.bits().max(self.denominator.bits()).saturating_sub(1000);
+        return self.numerator.shr(excess).to_f64() / self.denominator.shr(excess).to_f64()
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        return (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl Add for &Rational {
+    type Output = Rational;
+
+    fn add(self, other: &Rational) -> Rational {
+        let numerator: Integer = &self.numerator * &other.denominator + &other.numerator * &self.denominator;
+        return Rational::reduce(numerator, &self.denominator * &other.denominator)
+    }
+}
+
+impl Sub for &Rational {
+    type Output = Rational;
+
+    fn sub(self, other: &Rational) -> Rational {
+        return self + &-other
+    }
+}
+
+impl Mul for &Rational {
+    type Output = Rational;
+
+    fn mul(self, other: &Rational) -> Rational {
+        return Rational::reduce(&self.numerator * &other.numerator, &self.denominator * &other.denominator)
+    }
+}
+
+impl Div for &Rational {
+    type Output = Rational;
+
+    fn div(self, other: &Rational) -> Rational {
+        return Rational::reduce(&self.numerator * &other.denominator, &self.denominator * &other.numerator)
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        return Rational {
+            numerator: -&self.numerator,
+            denominator: self.denominator.clone(),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        return -&self
+    }
+}
+
+// The operators on owned numbers, which borrow them for the operators above.
+macro_rules! owned_operators {
+    ($($operator:ident $method:ident),*) => {
+        $(
+            impl $operator for Rational {
+                type Output = Rational;
+
+                fn $method(self, other: Rational) -> Rational {
+                    return (&self).$method(&other)
+                }
+            }
+
+            impl $operator<&Rational> for Rational {
+                type Output = Rational;
+
+                fn $method(self, other: &Rational) -> Rational {
+                    return (&self).$method(other)
+                }
+            }
+        )*
+    };
+}
+
+owned_operators!(Add add, Sub sub, Mul mul, Div div);
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, other: Rational) {
+        *self = &*self + &other;
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, other: Rational) {
+        *self = &*self * &other;
+    }
+}
+
+impl From<Integer> for Rational {
+    fn from(value: Integer) -> Rational {
+        return Rational {
+            numerator: value,
+            denominator: Integer::ONE,
+        }
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Rational {
+        return Rational::from(Integer::from(value))
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Rational {
+        return Rational::from(Integer::from(value))
+    }
+}
+
+// Floats are converted through their shortest decimal form, so 0.1 is 1/10 rather than the binary fraction closest to it. NaN and the
+// infinities have no decimal form, so they can't be converted.
+impl TryFrom<f32> for Rational {
+    type Error = String;
+
+    fn try_from(value: f32) -> Result<Rational, String> {
+        return value.to_string().parse::<Rational>()
+    }
+}
+
+impl TryFrom<f64> for Rational {
+    type Error = String;
+
+    fn try_from(value: f64) -> Result<Rational, String> {
+        return value.to_string().parse::<Rational>()
+    }
+}
+
+/// Reads an integer (`3`), a decimal (`-0.25`, `1e-3`) or a fraction (`1/3`).
+impl FromStr for Rational {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Rational, String> {
+        let invalid = || -> String {
+            return format!("{} isn't a number!", input)
+        };
+        let digits = |text: &str| -> Result<Integer, String> {
+            if text.is_empty() || !text.chars().all(|a| a.is_ascii_digit()) {
+                return Err(invalid())
+            }
+            return text.parse::<Integer>().map_err(|_| invalid())
+        };
+        let (negative, unsigned): (bool, &str) = match input.strip_prefix('-') {
+            Some(a) => (true, a),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+
+        let (numerator, denominator): (Integer, Integer) = if let Some((a, b)) = unsigned.split_once('/') {
+            (digits(a)?, digits(b)?)
+        } else {
+            // Split off the exponent, then the fractional digits
+            let (mantissa, exponent): (&str, i64) = match unsigned.split_once(['e', 'E']) {
+                Some((a, b)) => (a, b.parse::<i64>().map_err(|_| invalid())?),
+                None => (unsigned, 0),
+            };
+            let (whole, fraction): (&str, &str) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+            if whole.is_empty() && fraction.is_empty() || exponent.abs() > MAX_DECIMAL_EXPONENT {
+                return Err(invalid())
+            }
+            let numerator: Integer = digits(&format!("{}{}", whole, fraction))?;
+            let scale: i64 = exponent - fraction.len() as i64;
+            let power: Integer = Integer::from(10).pow(scale.unsigned_abs() as u32);
+            if scale >= 0 {
+                (numerator * power, Integer::ONE)
+            } else {
+                (numerator, power)
+            }
+        };
+        if denominator.is_zero() {
+            return Err(invalid())
+        }
+        if negative {
+            return Ok(Rational::reduce(-numerator, denominator))
+        }
+        return Ok(Rational::reduce(numerator, denominator))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            return write!(f, "{}", self.numerator)
+        }
+        return write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self)
+    }
+}
+
+// With the serde feature an integer that fits in an i64 is serialized as a number, and any other Rational as a string, eg. "1/3". Numbers
+// with a fractional part (eg. 0.5) are read exactly too.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rational {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let (true, Some(a)) = (self.is_integer(), self.numerator.to_i64()) {
+            return serializer.serialize_i64(a)
+        }
+        return serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rational {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rational, D::Error> {
+        struct RationalVisitor;
+
+        impl serde::de::Visitor<'_> for RationalVisitor {
+            type Value = Rational;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                return f.write_str("a number, or a fraction such as \"1/3\"")
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Rational, E> {
+                return Ok(Rational::from(value))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Rational, E> {
+                return Ok(Rational::from(Integer::from(value)))
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Rational, E> {
+                return value.to_string().parse::<Rational>().map_err(E::custom)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Rational, E> {
+                return value.parse::<Rational>().map_err(E::custom)
+            }
+        }
+
+        return deserializer.deserialize_any(RationalVisitor)
+    }
+}
@@ -0,0 +1,157 @@
+// S-expressions, a prefix format for Node that doesn't depend on precedence, eg. (+ (* 2 x) (^ y 3)). Every operator is written as a
+// list of its symbol and its two sides, and NUM and VAR values as they are. A VAR whose name would read as something else (a number, an
+// operator, or anything with spaces, parentheses or bars in it) is written between bars, eg. |my var|, with a backslash before any bar
+// or backslash in it.
+use super::Node;
+use super::error::ParseError;
+use super::rational::Rational;
+use super::numeric::Numeric;
+use super::token::Token;
+use std::iter::Peekable;
+use std::str::Chars;
+
+impl<N: Numeric> Node<N> {
+    /// The node as an S-expression, eg. `(+ (* 2 x) (^ y 3))`. `parse_sexpr` reads it back into the same Node.
+    pub fn to_sexpr(&self) -> String {
+        let mut sexpr: String = String::new();
+        write(self, &mut sexpr);
+        return sexpr
+    }
+}
+
+fn symbol<N: Numeric>(data_type: &Token<N>) -> Option<&'static str> {
+    match data_type {
+        Token::ADD => return Some("+"),
+        Token::MUL => return Some("*"),
+        Token::DIV => return Some("/"),
+        Token::EXP => return Some("^"),
+        _ => return None,
+    }
+}
+
+fn operator<N: Numeric>(symbol: &str) -> Option<Token<N>> {
+    match symbol {
+        "+" => return Some(Token::ADD),
+        "*" => return Some(Token::MUL),
+        "/" => return Some(Token::DIV),
+        "^" => return Some(Token::EXP),
+        _ => return None,
+    }
+}
+
+fn write<N: Numeric>(node: &Node<N>, sexpr: &mut String) {
+    match &node.data_type {
+        Token::NUM(a) => sexpr.push_str(&a.to_string()),
+        Token::VAR(a) => {
+            let name: &str = a.as_str();
+            let plain: bool = !name.is_empty()
+                && name.parse::<Rational>().is_err()
+                && operator::<N>(name).is_none()
+                && !name.contains(|b: char| b.is_whitespace() || b == '(' || b == ')' || b == '|');
+            if plain {
+                sexpr.push_str(name);
+            } else {
+                sexpr.push_str(&format!("|{}|", name.replace('\\', "\\\\").replace('|', "\\|")));
+            }
+        }
+        a => {
+            let symbol: &str = match symbol(a) {
+                Some(b) => b,
+                None => panic!("{:?} can't be part of an expression!", a),
+            };
+            sexpr.push('(');
+            sexpr.push_str(symbol);
+            for side in [&node.left, &node.right] {
+                match side {
+                    Some(b) => {
+                        sexpr.push(' ');
+                        write(b, sexpr);
+                    }
+                    None => panic!("The operator {:?} is missing a value!", a),
+                }
+            }
+            sexpr.push(')');
+        }
+    }
+}
+
+// A piece of an S-expression: a parenthesis, or an atom along with whether it was written between bars.
+enum Item {
+    Open,
+    Close,
+    Atom(String, bool),
+}
+
+// Read the next item, skipping the whitespace before it.
+fn next_item(chars: &mut Peekable<Chars>) -> Result<Option<Item>, ParseError> {
+    while chars.next_if(|a| a.is_whitespace()).is_some() {}
+    let first: char = match chars.next() {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    match first {
+        '(' => return Ok(Some(Item::Open)),
+        ')' => return Ok(Some(Item::Close)),
+        '|' => {
+            let mut name: String = String::new();
+            loop {
+                match chars.next() {
+                    Some('|') => return Ok(Some(Item::Atom(name, true))),
+                    Some('\\') => match chars.next() {
+                        Some(a) => name.push(a),
+                        None => return Err(ParseError::UnclosedBar),
+                    },
+                    Some(a) => name.push(a),
+                    None => return Err(ParseError::UnclosedBar),
+                }
+            }
+        }
+        a => {
+            let mut atom: String = a.to_string();
+            while let Some(b) = chars.next_if(|b| !b.is_whitespace() && *b != '(' && *b != ')' && *b != '|') {
+                atom.push(b);
+            }
+            return Ok(Some(Item::Atom(atom, false)))
+        }
+    }
+}
+
+fn read(chars: &mut Peekable<Chars>) -> Result<Node, ParseError> {
+    match next_item(chars)? {
+        Some(Item::Atom(a, true)) => return Ok(Node::var(&a)),
+        Some(Item::Atom(a, false)) => match a.parse::<Rational>() {
+            Ok(b) => return Ok(Node::num(b)),
+            Err(_) => return Ok(Node::var(&a)),
+        },
+        Some(Item::Open) => {
+            let data_type: Token = match next_item(chars)? {
+                Some(Item::Atom(a, false)) => match operator(&a) {
+                    Some(b) => b,
+                    None => return Err(ParseError::UnknownOperator(a)),
+                },
+                Some(Item::Atom(a, true)) => return Err(ParseError::UnknownOperator(a)),
+                Some(Item::Open) => return Err(ParseError::UnknownOperator("(".to_string())),
+                Some(Item::Close) => return Err(ParseError::UnknownOperator(")".to_string())),
+                None => return Err(ParseError::ExpectedClose),
+            };
+            let left: Node = read(chars)?;
+            let right: Node = read(chars)?;
+            match next_item(chars)? {
+                Some(Item::Close) => return Ok(Node::operation(data_type, left, right)),
+                _ => return Err(ParseError::ExpectedClose),
+            }
+        }
+        Some(Item::Close) => return Err(ParseError::UnexpectedClose),
+        None => return Err(ParseError::ExpectedValue),
+    }
+}
+
+/// Read an S-expression, eg. `(+ (* 2 x) (^ y 3))`, into its binary tree.
+pub fn parse(input: &str) -> Result<Box<Node>, ParseError> {
+    let mut chars: Peekable<Chars> = input.chars().peekable();
+    let node: Node = read(&mut chars)?;
+    if next_item(&mut chars)?.is_some() {
+        return Err(ParseError::TrailingInput)
+    }
+    return Ok(Box::new(node))
+}
@@ -0,0 +1,140 @@
+// An interned store of expressions. Every distinct subexpression is stored once and referred to by an ExprId, so equal subtrees share
+// one id: comparing two expressions is comparing two ids, and copying one is copying an id instead of a whole tree. Expr::simplify works
+// on the ids in a store, so a subexpression that appears more than once is only simplified once.
+use super::Node;
+use super::expr::Expr;
+use super::numeric::Numeric;
+use super::rational::Rational;
+use super::symbol::Symbol;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ExprId(u32);
+
+/// An expression in the store, the same kinds as Expr but holding the ids of its operands.
+#[derive(Debug)]
+#[derive(Clone)]
+pub enum Stored<N = Rational> {
+    Num(N),
+    Sym(Symbol),
+    Add(Vec<ExprId>),
+    Mul(Vec<ExprId>),
+    Pow(ExprId, ExprId),
+    Func(String, Vec<ExprId>),
+}
+
+// NUM values are compared and hashed the same way as in an Expr (see order.rs), so eg. 0.0 and -0.0 share an id.
+impl<N: Numeric> PartialEq for Stored<N> {
+    fn eq(&self, other: &Stored<N>) -> bool {
+        match (self, other) {
+            (Stored::Num(a), Stored::Num(b)) => return a.cmp_num(b) == std::cmp::Ordering::Equal,
+            (Stored::Sym(a), Stored::Sym(b)) => return a == b,
+            (Stored::Add(a), Stored::Add(b)) | (Stored::Mul(a), Stored::Mul(b)) => return a == b,
+            (Stored::Pow(a, b), Stored::Pow(c, d)) => return a == c && b == d,
+            (Stored::Func(a, b), Stored::Func(c, d)) => return a == c && b == d,
+            _ => return false,
+        }
+    }
+}
+
+impl<N: Numeric> Eq for Stored<N> {}
+
+impl<N: Numeric> Hash for Stored<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Stored::Num(a) => a.hash_num(state),
+            Stored::Sym(a) => a.hash(state),
+            Stored::Add(a) | Stored::Mul(a) => a.hash(state),
+            Stored::Pow(a, b) => {
+                a.hash(state);
+                b.hash(state);
+            }
+            Stored::Func(a, b) => {
+                a.hash(state);
+                b.hash(state);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct ExprStore<N = Rational> {
+    stored: Vec<Stored<N>>,
+    ids: HashMap<Stored<N>, ExprId>,
+}
+
+impl<N: Numeric> Default for ExprStore<N> {
+    fn default() -> ExprStore<N> {
+        return ExprStore {
+            stored: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<N: Numeric> ExprStore<N> {
+    /// An empty store.
+    pub fn new() -> ExprStore<N> {
+        return ExprStore::default()
+    }
+
+    /// The number of distinct expressions in the store.
+    pub fn len(&self) -> usize {
+        return self.stored.len()
+    }
+
+    /// Whether the store holds no expressions yet.
+    pub fn is_empty(&self) -> bool {
+        return self.stored.is_empty()
+    }
+
+    /// Look up the expression behind an id.
+    pub fn get(&self, id: ExprId) -> &Stored<N> {
+        return &self.stored[id.0 as usize]
+    }
+
+    /// Find the id of an expression, adding it to the store if it isn't there yet. Its operands must already be in the store.
+    pub fn intern(&mut self, stored: Stored<N>) -> ExprId {
+        if let Some(id) = self.ids.get(&stored) {
+            return *id
+        }
+        let id: ExprId = ExprId(self.stored.len() as u32);
+        self.stored.push(stored.clone());
+        self.ids.insert(stored, id);
+        return id
+    }
+
+    /// Add an expression and all of its subexpressions to the store.
+    pub fn insert(&mut self, expr: &Expr<N>) -> ExprId {
+        let stored: Stored<N> = match expr {
+            Expr::Num(a) => Stored::Num(a.clone()),
+            Expr::Sym(a) => Stored::Sym(*a),
+            Expr::Add(terms) => Stored::Add(terms.iter().map(|a| self.insert(a)).collect()),
+            Expr::Mul(factors) => Stored::Mul(factors.iter().map(|a| self.insert(a)).collect()),
+            Expr::Pow(base, exponent) => Stored::Pow(self.insert(base), self.insert(exponent)),
+            Expr::Func(name, arguments) => Stored::Func(name.clone(), arguments.iter().map(|a| self.insert(a)).collect()),
+        };
+        return self.intern(stored)
+    }
+
+    /// Add a Node and all of its branches to the store, converting it the same way as Expr::from.
+    pub fn insert_node(&mut self, node: &Node<N>) -> ExprId {
+        return self.insert(&Expr::from(node))
+    }
+
+    /// Rebuild the expression behind an id as a tree.
+    pub fn to_expr(&self, id: ExprId) -> Expr<N> {
+        match self.get(id) {
+            Stored::Num(a) => return Expr::Num(a.clone()),
+            Stored::Sym(a) => return Expr::Sym(*a),
+            Stored::Add(terms) => return Expr::Add(terms.iter().map(|a| self.to_expr(*a)).collect()),
+            Stored::Mul(factors) => return Expr::Mul(factors.iter().map(|a| self.to_expr(*a)).collect()),
+            Stored::Pow(base, exponent) => return Expr::Pow(Box::new(self.to_expr(*base)), Box::new(self.to_expr(*exponent))),
+            Stored::Func(name, arguments) => return Expr::Func(name.clone(), arguments.iter().map(|a| self.to_expr(*a)).collect()),
+        }
+    }
+}
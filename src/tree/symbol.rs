@@ -0,0 +1,105 @@
+// Interned variable names. Every name is stored once in a global table, and a Symbol points at that one copy of it, so copying a Symbol
+// doesn't allocate and checking two of them for equality is a pointer compare. The names are never freed, which keeps them borrowable
+// for as long as the program runs, and lets a Symbol read its name without going back to the table (or its lock.)
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{OnceLock, RwLock};
+
+#[derive(Clone, Copy)]
+pub struct Symbol(&'static str);
+
+fn table() -> &'static RwLock<HashSet<&'static str>> {
+    static TABLE: OnceLock<RwLock<HashSet<&'static str>>> = OnceLock::new();
+    return TABLE.get_or_init(|| RwLock::new(HashSet::new()))
+}
+
+impl Symbol {
+    /// The Symbol for a name, adding the name to the table if it hasn't been seen before.
+    pub fn new(name: &str) -> Symbol {
+        if let Some(name) = table().read().unwrap().get(name) {
+            return Symbol(name)
+        }
+
+        // Check again once writing, in case another thread added the name in between
+        let mut table = table().write().unwrap();
+        if let Some(name) = table.get(name) {
+            return Symbol(name)
+        }
+        let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+        table.insert(name);
+        return Symbol(name)
+    }
+
+    /// The name of the Symbol.
+    pub fn as_str(&self) -> &'static str {
+        return self.0
+    }
+}
+
+// Every name is in the table once, so two Symbols are the same exactly when they point at the same name.
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Symbol) -> bool {
+        return std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state);
+    }
+}
+
+// Symbols are ordered by their names, not by when they were added to the table, so that the canonical order doesn't depend on the
+// order expressions were read in.
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Symbol) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Symbol) -> Ordering {
+        if self == other {
+            return Ordering::Equal
+        }
+        return self.0.cmp(other.0)
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(name: &str) -> Symbol {
+        return Symbol::new(name)
+    }
+}
+
+// With the serde feature a Symbol is serialized as its name.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Symbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Symbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Symbol, D::Error> {
+        let name: String = serde::Deserialize::deserialize(deserializer)?;
+        return Ok(Symbol::new(&name))
+    }
+}
@@ -1,22 +1,31 @@
 // TODO: Add logic for VAR combination (eg. 32x) to be equivelant to [NUM(32.00), MUL, VAR("x")]
-// The Token type. Includes Addition operation, Multiplication operation, Division operation, Exponent operation, Variables, Numbers (as floats) and Grouping.
-use std::panic;
+// The Token type. Includes Addition operation, Multiplication operation, Division operation, Exponent operation, Variables, Numbers (as exact rationals), Grouping,
+// and the comparisons (<, <=, >, >=) used to chain inequalities.
+use super::error::ParseError;
+use super::numeric::Numeric;
+use super::rational::Rational;
+use super::symbol::Symbol;
 #[derive(Debug)]
 #[derive(Clone)]
-pub enum Token {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Token<N = Rational> {
     ADD,
     MUL,
     DIV,
     EXP,
-    VAR(String),
-    NUM(f32),
+    VAR(Symbol),
+    NUM(N),
     LGROUP,
     RGROUP,
+    LT,
+    LE,
+    GT,
+    GE,
 }
 
 // Creates a Token::ADD  when applicable, otherwise return None
-fn to_add(to_token: String) -> Option<Token> {
-    if to_token == "+".to_string() {
+fn to_add<N>(to_token: String) -> Option<Token<N>> {
+    if to_token == "+" {
         return Some(Token::ADD)
     } else {
         return None
@@ -24,8 +33,8 @@ fn to_add(to_token: String) -> Option<Token> {
 }
 
 // Creates a Token::MUL when applicable, otherwise return None
-fn to_multiply(to_token: String) -> Option<Token> {
-    if to_token == "*".to_string() {
+fn to_multiply<N>(to_token: String) -> Option<Token<N>> {
+    if to_token == "*" {
         return Some(Token::MUL)
     } else {
         return None
@@ -33,121 +42,108 @@ fn to_multiply(to_token: String) -> Option<Token> {
 }
 
 // Creates a Token::DIV when applicable, otherwise return None
-fn to_div(to_token: String) -> Option<Token> {
-    if to_token == "/".to_string() {
+fn to_div<N>(to_token: String) -> Option<Token<N>> {
+    if to_token == "/" {
         return Some(Token::DIV)
     } else {
         return None
     }
 }
 
-// Creates a Token::NUM when applicable, otherwise return None
-fn to_float(to_token: String) -> Option<Token> {
-    match to_token.parse::<f32>() {
-            Ok(f) => return Some(Token::NUM(f)),
-            Err(_e) => return None,
-        };
+// Creates a Token::LT, Token::LE, Token::GT or Token::GE when applicable, otherwise return None
+fn to_compare<N>(to_token: String) -> Option<Token<N>> {
+    match to_token.as_str() {
+        "<" => return Some(Token::LT),
+        "<=" => return Some(Token::LE),
+        ">" => return Some(Token::GT),
+        ">=" => return Some(Token::GE),
+        _ => return None,
+    }
+}
+
+impl<N> Token<N> {
+    /// Checks if a Token is one of the comparisons
+    pub fn is_comparison(&self) -> bool {
+        return matches!(self, Token::LT | Token::LE | Token::GT | Token::GE)
+    }
+}
+
+// Creates a Token::NUM when applicable, otherwise return None. Only a token with a digit in it is a number, so that names like inf and NaN
+// stay variables even when the numbers are floats, except for i, which is the imaginary unit when the numbers are complex.
+fn to_float<N: Numeric>(to_token: String) -> Option<Token<N>> {
+    if !to_token.bytes().any(|a| a.is_ascii_digit()) && to_token != "i" {
+        return None
+    }
+    return to_token.parse::<N>().ok().map(Token::NUM)
 }
 
 // Actually does the Tokenizing. Basically it takes the String and throws it at all the to_token functions. After it makes it way through the if statements,
 // it will eventually return a Token type.
-fn tokenizer(to_token: String) -> Token {
-    let mut a = to_float(to_token.clone());
-    if a.is_none() {
-        a = to_add(to_token.clone());
-        if a.is_none() {
-            a = to_multiply(to_token.clone());
-            if a.is_none() {
-                a = to_div(to_token.clone());
-                if a.is_none() {
-                    return Token::VAR(to_token.clone())
-                } else {
-                    return a.unwrap()
-                }
-            } else {
-                return a.unwrap()
-            }
-        } else {
-            return a.unwrap()
-        }
-    } else {
-        return a.unwrap()
+fn tokenizer<N: Numeric>(to_token: String) -> Token<N> {
+    let a: Option<Token<N>> = to_float(to_token.clone())
+        .or_else(|| to_add(to_token.clone()))
+        .or_else(|| to_multiply(to_token.clone()))
+        .or_else(|| to_div(to_token.clone()))
+        .or_else(|| to_compare(to_token.clone()));
+    match a {
+        Some(a) => return a,
+        None => return Token::VAR(Symbol::new(&to_token)),
     }
 }
 
-// Called when you want to Tokenize your vector of Strings.
-pub fn tokenize(string_vector: Vec<String>) -> Vec<Token> {
-    let mut token_vector: Vec<Token> = Vec::new(); 
+/// Called when you want to Tokenize your vector of Strings.
+pub fn tokenize<N: Numeric>(string_vector: Vec<String>) -> Vec<Token<N>> {
+    let mut token_vector: Vec<Token<N>> = Vec::new(); 
     for x in string_vector.iter() {
         let token;
-        let size_of_x: usize = x.as_str().len();
 
         // If it begins with a "(", continue
-        if x.as_str().get(0..1).unwrap() == "(" {
-            let mut past: i32 = 0;
+        if x.starts_with('(') {
             // Push All LGROUP values.
-            for a in 1..x.as_str().len() as i32 {
-                if x.as_str().get(past as usize..a as usize).unwrap() == "(" {
-                    past = a;
-                    token_vector.push(Token::LGROUP);
-                } else {
-                    break;
-                }
+            let rest: &str = x.trim_start_matches('(');
+            for _ in 0..x.len() - rest.len() {
+                token_vector.push(Token::LGROUP);
             }
             // if there is stuff after "(", push it.
-            if x.as_str().get(past as usize..).unwrap() != "(" {
-                token = tokenizer(x.as_str().get(past as usize..).unwrap().to_string());
+            if !rest.is_empty() {
+                token = tokenizer(rest.to_string());
                 token_vector.push(token);
-            } else {
-                token_vector.push(Token::LGROUP);
-            }
-            
-        // Else If it ends with a ")", continue
-        } else if x.as_str().get(size_of_x - 1..size_of_x).unwrap() == ")" {
-            let mut past: i32 = 0;
-            // find first occurrence of )
-            for a in 1..(x.as_str().len() + 1) as i32 {
-                if x.as_str().get(past as usize..a as usize).unwrap() == ")" {
-                    break;
-                }
-                past = a;
             }
 
-            // if there is stuff before ")", push it.
-            if past != 0 && x.as_str().get(0..past as usize).unwrap().to_string() != ")" {
-                token = tokenizer(x.as_str().get(0..past as usize).unwrap().to_string());
+        // Else If it ends with a ")", continue
+        } else if x.ends_with(')') {
+            // if there is stuff before the first ")", push it.
+            let past: usize = x.find(')').unwrap();
+            if past != 0 {
+                token = tokenizer(x[..past].to_string());
                 token_vector.push(token);
             }
-            past = 0;
 
             // Push all ")"
-            for a in 1..(x.as_str().len() + 1) as i32 {
-                if x.as_str().get(past as usize..a as usize).unwrap() == ")" {
-                    token_vector.push(Token::RGROUP);
-                }
-                past = a;
+            for _ in x.matches(')') {
+                token_vector.push(Token::RGROUP);
             }
-            
+
         // Else If it begins with a "-", continue
-        } else if x.as_str().get(0..1).unwrap() == "-" {
+        } else if x.starts_with('-') {
             // If there is stuff after "-", push the equivelant expression -1 * x, where x is what follows after "-"
-            if x.as_str().get(1..).unwrap().to_string().is_empty() != true { 
-                token_vector.push(Token::NUM(-1.00));
+            if !x.as_str().get(1..).unwrap().to_string().is_empty() { 
+                token_vector.push(Token::NUM(N::from_integer(-1)));
                 token_vector.push(Token::MUL);
                 token = tokenizer(x.as_str().get(1..).unwrap().to_string());
                 token_vector.push(token);
             // if there isn't stuff after "-", push + -1 *
             } else {
                 token_vector.push(Token::ADD);
-                token_vector.push(Token::NUM(-1.00));
+                token_vector.push(Token::NUM(N::from_integer(-1)));
                 token_vector.push(Token::MUL);
             }
         // Else If it begins with a "^", continue
-        } else if x.as_str().get(0..1).unwrap() == "^" {
+        } else if x.starts_with('^') {
             // Push exponent token.
             token_vector.push(Token::EXP);
             // If there is stuff after "^", push the stuff.
-            if x.as_str().get(1..).unwrap().to_string().is_empty() != true {
+            if !x.as_str().get(1..).unwrap().to_string().is_empty() {
                 token_vector.push(tokenizer(x.as_str().get(1..).unwrap().to_string()));
             }
         // Else, tokenize and push x
@@ -160,184 +156,74 @@ pub fn tokenize(string_vector: Vec<String>) -> Vec<Token> {
     return token_vector
 }
 
-fn rm_sides_add_mul(mut token_vector: Vec<Token>, mut group_locations: Vec<(i32, i32)>) -> (Vec<Token>, Vec<(i32, i32)>) {
-    // Check for "useless group" (on the outskirts of the equation) and removes them, repeats just in case there are multiple of them.
-    while group_locations.is_empty() != true && group_locations[0].0 == 0 && group_locations[0].1 == (token_vector.len() - 1) as i32 {
-        token_vector.remove(0 as usize);
-        token_vector.remove((token_vector.len() - 1) as usize);
-        group_locations.remove(0 as usize);
-        group_locations = find_groups(token_vector.clone());
-    }
-
-
-    let a: i32 = group_locations.len() as i32;
-    let mut i: i32 = 0;
-    let mut b: bool = false;
-
-    while a > i {
-        for a in group_locations.clone() {
-            if a.0 > 0 {
-                match token_vector[(a.0 - 1) as usize] {
-                    // Check for NUM, VAR, or RGROUP value before LGROUP value, and if so add a MUL Value between them.
-                    Token::NUM(_) => {
-                        token_vector.insert(a.0 as usize, Token::MUL);
-                        b = true;
-                        break;
-                    }
-                    Token::VAR(_) => {
-                        token_vector.insert(a.0 as usize, Token::MUL);
-                        b = true;
-                        break;
-                    }
-                    Token::RGROUP => {
-                        token_vector.insert(a.0 as usize, Token::MUL);
-                        b = true;
-                        break;
-                    }
-                    // If there is an ADD parameter before a LGROUP, remove the LGROUP and its respective RGROUP value.
-                    Token::ADD => {
-                        token_vector.remove(a.1 as usize);
-                        token_vector.remove(a.0 as usize);
-                        b = true;
-                        break;
-                    }
-                    _ => {}
-
-                }
-            }
-        }
-        if b == true {
-            group_locations = find_groups(token_vector.clone());
-            b = false;
-        }
-        i += 1;
-    }
-
-    return (token_vector, group_locations)
-}
-
-// Find groupings.
-fn find_groups(token_vector: Vec<Token>) -> Vec<(i32, i32)> {
-    let mut total_group: i32 = 0;
-
-    // find total LGROUP and RGROUP
-    for i in 0..token_vector.len() as i32 {
-        match token_vector[i as usize] {
-            Token::LGROUP => {
-                total_group += 1;
-            } Token::RGROUP => {
-                total_group += 1;
-            }
-            _ => {}
-        }
-    }
-
-    // If the total group is uneven, crash.
-    if total_group % 2 != 0 {
-        panic!("There must be an even number of group symbols!")
-    }
-
-    // Declare group_locations
+/// Find groupings with a single stack pass. Every LGROUP reserves its slot in group_locations when it is seen, so the locations come out
+/// already sorted by their left value, and its RGROUP fills in the right value once it is reached.
+pub fn find_groups<N>(token_vector: &[Token<N>]) -> Result<Vec<(i32, i32)>, ParseError> {
     let mut group_locations: Vec<(i32, i32)> = Vec::new();
+    let mut open_groups: Vec<usize> = Vec::new();
 
-    // if there isn't any groups at all, just return the empty vector
-    if total_group == 0 {
-        return group_locations
-    }
-
-    // declare variables logic for the locating of parenthesis beginning and end locations
-    let mut unsorted_lgroup_locations: Vec<i32> = Vec::new();
-    let mut left_right_value: usize = 0;
-    let mut right_right_value: usize = 0;
-
-    // search for all left values before the first right
-    for i in 0..token_vector.len() as i32 {
-        match token_vector[i as usize] {
+    for (i, x) in token_vector.iter().enumerate() {
+        match x {
             Token::LGROUP => {
-                unsorted_lgroup_locations.push(i);
-            } Token::RGROUP => {
-                left_right_value = i as usize;
-                // if there is only one left value, push it and return group_locations
-                if total_group / 2 == 1 {
-                    group_locations.push((unsorted_lgroup_locations.pop().unwrap(), left_right_value as i32));
-                    return group_locations
+                open_groups.push(group_locations.len());
+                group_locations.push((i as i32, -1));
+            }
+            Token::RGROUP => {
+                // An RGROUP without an LGROUP to close is an error.
+                match open_groups.pop() {
+                    Some(slot) => group_locations[slot].1 = i as i32,
+                    None => return Err(ParseError::UnbalancedGroups),
                 }
-                // break unless so as to search for the rest
-                break;
             }
             _ => {}
         }
     }
 
-    // Loop over until the group locations are empty
-    while unsorted_lgroup_locations.is_empty() != true {
-        // push right most left and known right
-        group_locations.push((unsorted_lgroup_locations.pop().unwrap(), left_right_value as i32));
+    // If an LGROUP was never closed, that is an error too.
+    if !open_groups.is_empty() {
+        return Err(ParseError::UnbalancedGroups)
+    }
+    return Ok(group_locations)
+}
 
-        // look for next right value between left and token_vector.len(), whilst also marking down left values
-        for i in left_right_value as i32 + 1..token_vector.len() as i32 {
-            match token_vector[i as usize] {
-                Token::LGROUP => {
-                    unsorted_lgroup_locations.push(i as i32);
-                }
-                Token::RGROUP => {
-                    // if the right value in question isn't the last one we searched for, continue
-                    if i != left_right_value as i32 {
-                        // Set the new right value as right_right_value and break
-                        right_right_value = i as usize;
-                        break;
-                    }
+/// Orchestrates the group fixes and returns the fixed Vector and Group locations. This is done in a single pass over the vector:
+/// - A MUL value is added between a NUM, VAR, or RGROUP value and the LGROUP value that follows it.
+/// - "Useless groups" wrapping the entire equation are removed afterwards, which only needs the (already sorted) group locations.
+///
+/// A group after an ADD value used to be removed as well, but that is only safe when nothing binds tighter than the ADD right after the
+/// group: 1 + ( 2 + 3 ) * 4 would become 1 + 2 + 3 * 4. The group is kept instead, and the simplifier flattens a sum inside of a sum.
+#[allow(clippy::type_complexity)]
+pub fn fix_groups<N>(token_vector: Vec<Token<N>>) -> Result<(Vec<Token<N>>, Vec<(i32, i32)>), ParseError> {
+    let mut fixed_vector: Vec<Token<N>> = Vec::with_capacity(token_vector.len());
+    for x in token_vector {
+        if let Token::LGROUP = x {
+            match fixed_vector.last() {
+                Some(Token::NUM(_)) | Some(Token::VAR(_)) | Some(Token::RGROUP) => {
+                    fixed_vector.push(Token::MUL);
                 }
                 _ => {}
             }
         }
-        // set left_right_value as the right_right_value, thus moving the block over.
-        left_right_value = right_right_value;
+        fixed_vector.push(x);
     }
+    let mut group_locations: Vec<(i32, i32)> = find_groups(&fixed_vector)?;
 
-    // Declare variables for sorting.
-    let mut sorted_group_locations: Vec<(i32, i32)> = Vec::new();
-    sorted_group_locations.push((-1, -1));
-    let mut next: (i32, i32);
-    let mut insertion: i32;
-    let mut k: i32;
-    let mut copy: (i32, i32);
+    // Count the useless groups. The n-th group (sorted by left value) is useless if it spans from n to the n-th last value.
+    let last: i32 = fixed_vector.len() as i32 - 1;
+    let mut useless: usize = 0;
+    while useless < group_locations.len() && group_locations[useless] == (useless as i32, last - useless as i32) && (useless as i32) < last - useless as i32 {
+        useless += 1;
+    }
 
-    // Sort the group_locations vector by left value small to large.
-    for i in 0..group_locations.len() as i32 {
-        next = group_locations[i as usize];
-        insertion = 0;
-        k = i;
-        while k > 0 && insertion == 0 {
-            if next.0 > sorted_group_locations[k as usize - 1].0 {
-                insertion = k;
-            } else {
-                if k == sorted_group_locations.len() as i32 {
-                    sorted_group_locations.push(sorted_group_locations[k as usize - 1]);
-                } else {
-                    copy = sorted_group_locations[(k as usize) - 1];
-                    let _ = std::mem::replace(&mut sorted_group_locations[k as usize], copy);
-                }
-            }
-            k -= 1;
-        }
-        if insertion == sorted_group_locations.len() as i32 {
-            sorted_group_locations.push(next);
-        } else {
-            let _ = std::mem::replace(&mut sorted_group_locations[insertion as usize], next);
+    // Remove them from both ends, and shift the remaining group locations over.
+    if useless > 0 {
+        fixed_vector.truncate(fixed_vector.len() - useless);
+        fixed_vector.drain(0..useless);
+        group_locations.drain(0..useless);
+        for a in group_locations.iter_mut() {
+            a.0 -= useless as i32;
+            a.1 -= useless as i32;
         }
     }
-    return sorted_group_locations
+    return Ok((fixed_vector, group_locations))
 }
-
-// Orchestrates the group fixes and returns the fixed Vector and Group locations.
-pub fn fix_groups(mut token_vector: Vec<Token>) -> (Vec<Token>, Vec<(i32, i32)>) {
-    let mut group_locations: Vec<(i32, i32)> = find_groups(token_vector.clone());
-    if group_locations.is_empty() != true {
-        let a: (Vec<Token>, Vec<(i32, i32)>);
-        a = rm_sides_add_mul(token_vector.clone(), group_locations.clone());
-        token_vector = a.0;
-        group_locations = a.1;
-    }
-    return (token_vector, group_locations)
-}
\ No newline at end of file
@@ -0,0 +1,148 @@
+// Traversal of the typed expression tree. A Visitor looks at every subexpression, a MutVisitor can change them in place, and a Fold
+// rebuilds the expression from the bottom up. Each trait walks every operand by default, so an analysis or transformation only has to
+// override the kinds of expression it cares about, and can call walk, walk_mut or fold_operands to keep going past them.
+use super::expr::Expr;
+use super::numeric::Numeric;
+use super::rational::Rational;
+use super::symbol::Symbol;
+use std::collections::BTreeSet;
+
+/// Looks at an expression and all of its subexpressions, parents before their operands.
+pub trait Visitor<N: Numeric = Rational> {
+    fn visit(&mut self, expr: &Expr<N>) {
+        walk(self, expr);
+    }
+
+    fn visit_num(&mut self, _num: &N) {}
+
+    fn visit_sym(&mut self, _sym: Symbol) {}
+}
+
+/// Visit the operands of an expression, or hand a NUM or symbol to visit_num or visit_sym.
+pub fn walk<N: Numeric, V: Visitor<N> + ?Sized>(visitor: &mut V, expr: &Expr<N>) {
+    match expr {
+        Expr::Num(a) => visitor.visit_num(a),
+        Expr::Sym(a) => visitor.visit_sym(*a),
+        Expr::Add(operands) | Expr::Mul(operands) | Expr::Func(_, operands) => {
+            for a in operands {
+                visitor.visit(a);
+            }
+        }
+        Expr::Pow(base, exponent) => {
+            visitor.visit(base);
+            visitor.visit(exponent);
+        }
+    }
+}
+
+/// Changes an expression and its subexpressions in place, parents before their operands.
+pub trait MutVisitor<N: Numeric = Rational> {
+    fn visit_mut(&mut self, expr: &mut Expr<N>) {
+        walk_mut(self, expr);
+    }
+}
+
+/// Visit the operands of an expression mutably.
+pub fn walk_mut<N: Numeric, V: MutVisitor<N> + ?Sized>(visitor: &mut V, expr: &mut Expr<N>) {
+    match expr {
+        Expr::Num(_) | Expr::Sym(_) => {}
+        Expr::Add(operands) | Expr::Mul(operands) | Expr::Func(_, operands) => {
+            for a in operands {
+                visitor.visit_mut(a);
+            }
+        }
+        Expr::Pow(base, exponent) => {
+            visitor.visit_mut(base);
+            visitor.visit_mut(exponent);
+        }
+    }
+}
+
+/// Rebuilds an expression, taking it by value and returning its replacement.
+pub trait Fold<N: Numeric = Rational> {
+    fn fold(&mut self, expr: Expr<N>) -> Expr<N> {
+        return fold_operands(self, expr)
+    }
+}
+
+/// Rebuild an expression with each of its operands folded.
+pub fn fold_operands<N: Numeric, F: Fold<N> + ?Sized>(folder: &mut F, expr: Expr<N>) -> Expr<N> {
+    match expr {
+        Expr::Num(_) | Expr::Sym(_) => return expr,
+        Expr::Add(terms) => return Expr::Add(terms.into_iter().map(|a| folder.fold(a)).collect()),
+        Expr::Mul(factors) => return Expr::Mul(factors.into_iter().map(|a| folder.fold(a)).collect()),
+        Expr::Pow(base, exponent) => return Expr::Pow(Box::new(folder.fold(*base)), Box::new(folder.fold(*exponent))),
+        Expr::Func(name, arguments) => return Expr::Func(name, arguments.into_iter().map(|a| folder.fold(a)).collect()),
+    }
+}
+
+// The symbols an expression depends on.
+struct FreeSymbols {
+    symbols: BTreeSet<Symbol>,
+}
+
+impl<N: Numeric> Visitor<N> for FreeSymbols {
+    fn visit_sym(&mut self, sym: Symbol) {
+        self.symbols.insert(sym);
+    }
+}
+
+// The number of levels in an expression, a NUM or symbol is one level.
+struct Depth {
+    current: usize,
+    deepest: usize,
+}
+
+impl<N: Numeric> Visitor<N> for Depth {
+    fn visit(&mut self, expr: &Expr<N>) {
+        self.current += 1;
+        self.deepest = self.deepest.max(self.current);
+        walk(self, expr);
+        self.current -= 1;
+    }
+}
+
+// Replace every occurrence of a symbol with an expression.
+struct Substitute<'a, N> {
+    sym: Symbol,
+    replacement: &'a Expr<N>,
+}
+
+impl<N: Numeric> Fold<N> for Substitute<'_, N> {
+    fn fold(&mut self, expr: Expr<N>) -> Expr<N> {
+        match expr {
+            Expr::Sym(a) if a == self.sym => return self.replacement.clone(),
+            a => return fold_operands(self, a),
+        }
+    }
+}
+
+impl<N: Numeric> Expr<N> {
+    /// The symbols the expression depends on, sorted by name.
+    pub fn free_symbols(&self) -> BTreeSet<Symbol> {
+        let mut visitor: FreeSymbols = FreeSymbols {
+            symbols: BTreeSet::new(),
+        };
+        visitor.visit(self);
+        return visitor.symbols
+    }
+
+    /// The number of levels in the expression, a NUM or symbol is one level.
+    pub fn depth(&self) -> usize {
+        let mut visitor: Depth = Depth {
+            current: 0,
+            deepest: 0,
+        };
+        visitor.visit(self);
+        return visitor.deepest
+    }
+
+    /// Replace every occurrence of `sym` with `replacement`.
+    pub fn substitute(&self, sym: Symbol, replacement: &Expr<N>) -> Expr<N> {
+        let mut folder: Substitute<N> = Substitute {
+            sym: sym,
+            replacement: replacement,
+        };
+        return folder.fold(self.clone())
+    }
+}
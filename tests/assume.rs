@@ -0,0 +1,43 @@
+use rust_cas::{parse, simplify_with, sym, Assumption, Assumptions};
+
+fn simplified(input: &str, assumptions: &Assumptions) -> String {
+    simplify_with(&parse(input).unwrap(), assumptions).to_string()
+}
+
+fn assuming(name: &str, assumption: Assumption) -> Assumptions {
+    let mut assumptions = Assumptions::new();
+    assumptions.assume(name, assumption);
+    assumptions
+}
+
+#[test]
+fn positive_implies_nonzero() {
+    let positive = assuming("x", Assumption::Positive);
+    assert!(positive.is_nonzero(&sym("x")));
+    assert!(positive.is_real(&sym("x")));
+    assert!(!positive.is_nonzero(&sym("y")));
+    assert_eq!(simplified("x / x", &positive), "1");
+}
+
+#[test]
+fn division_without_assumptions() {
+    assert_eq!(simplified("x / x", &Assumptions::new()), "x / x");
+    assert_eq!(simplified("x / x", &assuming("y", Assumption::Nonzero)), "x / x");
+    assert_eq!(simplified("x / x", &assuming("x", Assumption::Real)), "x / x");
+    assert_eq!(simplified("x / x", &assuming("x", Assumption::Integer)), "x / x");
+}
+
+#[test]
+fn division_of_powers() {
+    let nonzero = assuming("x", Assumption::Nonzero);
+    assert_eq!(simplified("x ^ 2 / x", &nonzero), "x");
+    assert_eq!(simplified("x / x ^ 3", &nonzero), "x ^ (-2)");
+    assert_eq!(simplified("x ^ 2 / x", &Assumptions::new()), "x ^ 2 / x");
+}
+
+#[test]
+fn roots_of_powers() {
+    assert_eq!(simplified("( x ^ 2 ) ^ ( 1 / 2 )", &assuming("x", Assumption::Positive)), "x");
+    assert_eq!(simplified("( x ^ 2 ) ^ ( 1 / 2 )", &assuming("x", Assumption::Nonzero)), "(x ^ 2) ^ (1 / 2)");
+    assert_eq!(simplified("( x ^ 2 ) ^ ( 1 / 2 )", &Assumptions::new()), "(x ^ 2) ^ (1 / 2)");
+}
@@ -0,0 +1,60 @@
+use rust_cas::{parse, parse_as, simplify, BigFloat, Complex, Language, Rational};
+
+#[test]
+fn literals() {
+    assert_eq!(parse("3 * x").unwrap().to_code(Language::C), "3.0 * x");
+    assert_eq!(parse_as::<f64>("0.5 * x").unwrap().to_code(Language::Python), "0.5 * x");
+    let large = parse_as::<BigFloat>("1e1000 * x").unwrap();
+    assert_eq!(large.to_code(Language::C), "1e1000 * x");
+    assert_eq!(large.to_code(Language::Python), "1e1000 * x");
+}
+
+#[test]
+fn complex_literals() {
+    let node = simplify(&parse_as::<Complex<Rational>>("( 3 + 2 * i ) * x + y ^ ( 0 - i )").unwrap());
+    assert_eq!(node.to_code(Language::Python), "y ** (-1.0j) + (3.0 + 2.0j) * x");
+    assert_eq!(node.to_code(Language::C), "cpow(y, -I) + (3.0 + 2.0 * I) * x");
+    assert!(node.to_function("f", Language::C).starts_with("double complex f(double x, double y) {"));
+    let real = parse_as::<Complex<Rational>>("x ^ 2").unwrap();
+    assert_eq!(real.to_code(Language::C), "pow(x, 2.0)");
+    assert_eq!(real.to_function("f", Language::C), "double f(double x) {\n    return pow(x, 2.0);\n}\n");
+}
+
+#[test]
+#[should_panic(expected = "Rust has no complex numbers")]
+fn complex_rust() {
+    parse_as::<Complex<Rational>>("2 * i * x").unwrap().to_code(Language::Rust);
+}
+
+#[test]
+fn keywords() {
+    let node = parse("fn + x").unwrap();
+    assert_eq!(node.to_code(Language::Rust), "r#fn + x");
+    assert_eq!(node.to_function("type", Language::Rust), "pub fn r#type(r#fn: f64, x: f64) -> f64 {\n    r#fn + x\n}\n");
+    assert_eq!(parse("lambda * 2").unwrap().to_code(Language::C), "2.0 * lambda");
+    assert_eq!(parse("double * 2").unwrap().to_function("f", Language::Python), "def f(double):\n    return 2.0 * double\n");
+}
+
+#[test]
+#[should_panic(expected = "lambda isn't an identifier in Python")]
+fn python_keyword() {
+    parse("lambda * 2").unwrap().to_code(Language::Python);
+}
+
+#[test]
+#[should_panic(expected = "double isn't an identifier in C")]
+fn c_keyword() {
+    parse("x + 1").unwrap().to_function("double", Language::C);
+}
+
+#[test]
+#[should_panic(expected = "x' isn't an identifier in Rust")]
+fn invalid_name() {
+    parse("x' + 1").unwrap().to_code(Language::Rust);
+}
+
+#[test]
+#[should_panic(expected = "self can't be a raw identifier in Rust")]
+fn raw_keyword() {
+    parse("self + 1").unwrap().to_code(Language::Rust);
+}
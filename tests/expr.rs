@@ -0,0 +1,17 @@
+use rust_cas::{num, parse, sym, Expr, Node};
+use std::convert::TryFrom;
+
+#[test]
+fn to_node() {
+    let node: Node = *parse("2 * x / y + x ^ 3").unwrap();
+    let expr: Expr = Expr::from(&node);
+    assert_eq!(*expr.to_node().unwrap(), node);
+    assert_eq!(Node::try_from(&expr), Ok(node));
+    assert_eq!(*(num(1) + sym("x")).to_node().unwrap(), *parse("x + 1").unwrap());
+
+    // A function has no Token, so an expression with one anywhere in it can't be stored in a Node
+    let function: Expr = Expr::Func("sin".to_string(), vec![sym("x")]);
+    assert_eq!(function.to_node(), Err("The function sin can't be stored in a Node!".to_string()));
+    assert!(Node::try_from(&(sym("y") * function.clone().pow(2))).is_err());
+    assert!(Node::try_from(&Expr::Add(vec![sym("x"), function])).is_err());
+}
@@ -0,0 +1,52 @@
+use rust_cas::{parse, parse_inequality, Node, Rational, Token};
+
+fn latex(input: &str) -> String {
+    parse(input).unwrap().to_latex()
+}
+
+#[test]
+fn fractions() {
+    assert_eq!(latex("x / y"), "\\frac{x}{y}");
+    // The sides of a \frac are grouped by its braces, so they never need parentheses
+    assert_eq!(latex("( x + 1 ) / ( y - 2 )"), "\\frac{1 + x}{y - 2}");
+    assert_eq!(latex("x / y / z"), "\\frac{\\frac{x}{y}}{z}");
+    assert_eq!(latex("1 / ( x / y )"), "\\frac{1}{\\frac{x}{y}}");
+}
+
+#[test]
+fn superscripts() {
+    assert_eq!(latex("x ^ 2"), "x^{2}");
+    // The exponent is grouped by the superscript, the base isn't
+    assert_eq!(latex("x ^ ( y + 1 )"), "x^{1 + y}");
+    assert_eq!(latex("x ^ ( 1 / 2 )"), "x^{\\frac{1}{2}}");
+    assert_eq!(latex("( x + 1 ) ^ 2"), "\\left(1 + x\\right)^{2}");
+    assert_eq!(latex("( 2 ^ 3 ) ^ 4"), "\\left(2^{3}\\right)^{4}");
+}
+
+#[test]
+fn products() {
+    // Factors sit side by side unless that would run two numbers together
+    assert_eq!(latex("2 * x"), "2 x");
+    assert_eq!(latex("x * y"), "x y");
+    assert_eq!(latex("-2 * x"), "-2 x");
+    assert_eq!(latex("2 * 3"), "2 \\cdot 3");
+    assert_eq!(latex("x * ( y + 1 )"), "x \\left(1 + y\\right)");
+    assert_eq!(latex("( x + 1 ) * ( y + 1 )"), "\\left(1 + x\\right) \\left(1 + y\\right)");
+    assert_eq!(latex("x - y"), "x - y");
+    let negative: Node = Node::operation(Token::MUL, Node::var("x"), Node::num(Rational::from(-3)));
+    assert_eq!(negative.to_latex(), "x \\left(-3\\right)");
+}
+
+#[test]
+fn names() {
+    assert_eq!(latex("alpha * beta"), "\\alpha \\beta");
+    assert_eq!(latex("velocity * x"), "\\mathrm{velocity} x");
+    assert_eq!(latex("theta ^ 2"), "\\theta^{2}");
+}
+
+#[test]
+fn inequalities() {
+    assert_eq!(parse_inequality("0 < x <= 10").unwrap().to_latex(), "0 < x \\leq 10");
+    assert_eq!(parse_inequality("y >= x > 1").unwrap().to_latex(), "y \\geq x > 1");
+    assert_eq!(parse_inequality("0 <= x / 2").unwrap().to_latex(), "0 \\leq \\frac{x}{2}");
+}
@@ -0,0 +1,62 @@
+use rust_cas::{parse, parse_inequality, Node, Rational, Token};
+
+// The MathML of an expression, without the <math> element around it
+fn mathml(node: &Node) -> String {
+    let mathml: String = node.to_mathml();
+    let inner: &str = mathml.strip_prefix("<math xmlns=\"http://www.w3.org/1998/Math/MathML\">").unwrap();
+    inner.strip_suffix("</math>").unwrap().to_string()
+}
+
+fn parsed(input: &str) -> String {
+    mathml(&parse(input).unwrap())
+}
+
+#[test]
+fn fractions() {
+    assert_eq!(parsed("x / y"), "<mfrac><mi>x</mi><mi>y</mi></mfrac>");
+    assert_eq!(parsed("( x + 1 ) / y"), "<mfrac><mrow><mn>1</mn><mo>+</mo><mi>x</mi></mrow><mi>y</mi></mfrac>");
+    assert_eq!(parsed("x / y / z"), "<mfrac><mfrac><mi>x</mi><mi>y</mi></mfrac><mi>z</mi></mfrac>");
+}
+
+#[test]
+fn superscripts() {
+    assert_eq!(parsed("x ^ 2"), "<msup><mi>x</mi><mn>2</mn></msup>");
+    assert_eq!(parsed("x ^ ( 1 / 2 )"), "<msup><mi>x</mi><mfrac><mn>1</mn><mn>2</mn></mfrac></msup>");
+    assert_eq!(parsed("( x + 1 ) ^ 2"), "<msup><mrow><mo>(</mo><mrow><mn>1</mn><mo>+</mo><mi>x</mi></mrow><mo>)</mo></mrow><mn>2</mn></msup>");
+}
+
+#[test]
+fn products() {
+    // An invisible times where the factors can sit side by side, and a visible one between two numbers
+    assert_eq!(parsed("2 * x"), "<mrow><mn>2</mn><mo>&#x2062;</mo><mi>x</mi></mrow>");
+    assert_eq!(parsed("x * y"), "<mrow><mi>x</mi><mo>&#x2062;</mo><mi>y</mi></mrow>");
+    assert_eq!(parsed("2 * 3"), "<mrow><mn>2</mn><mo>&#xD7;</mo><mn>3</mn></mrow>");
+    assert_eq!(parsed("x - y"), "<mrow><mi>x</mi><mo>&#x2212;</mo><mi>y</mi></mrow>");
+}
+
+#[test]
+fn negative_numbers() {
+    // The sign is an operator of its own, <mn> only holds the digits
+    assert_eq!(parsed("-3"), "<mrow><mo form=\"prefix\">&#x2212;</mo><mn>3</mn></mrow>");
+    assert_eq!(parsed("-2 * x"), "<mrow><mrow><mo form=\"prefix\">&#x2212;</mo><mn>2</mn></mrow><mo>&#x2062;</mo><mi>x</mi></mrow>");
+    let negative: Node = Node::operation(Token::MUL, Node::var("x"), Node::num(Rational::from(-3)));
+    let expected: &str = "<mrow><mi>x</mi><mo>&#x2062;</mo><mrow><mo>(</mo><mrow><mo form=\"prefix\">&#x2212;</mo><mn>3</mn></mrow><mo>)</mo></mrow></mrow>";
+    assert_eq!(mathml(&negative), expected);
+}
+
+#[test]
+fn names() {
+    assert_eq!(parsed("alpha * velocity"), "<mrow><mi>α</mi><mo>&#x2062;</mo><mi>velocity</mi></mrow>");
+    // Characters that can't appear in XML text as they are are escaped
+    assert_eq!(mathml(&Node::var("a<b&c>d")), "<mi>a&lt;b&amp;c&gt;d</mi>");
+    assert_eq!(mathml(&Node::var("&amp;")), "<mi>&amp;amp;</mi>");
+}
+
+#[test]
+fn inequalities() {
+    let prefix: &str = "<math xmlns=\"http://www.w3.org/1998/Math/MathML\"><mrow>";
+    let chain: String = parse_inequality("0 < x <= 10").unwrap().to_mathml();
+    assert_eq!(chain, format!("{}<mn>0</mn><mo>&lt;</mo><mi>x</mi><mo>&#x2264;</mo><mn>10</mn></mrow></math>", prefix));
+    let chain: String = parse_inequality("y >= x > 1").unwrap().to_mathml();
+    assert_eq!(chain, format!("{}<mi>y</mi><mo>&#x2265;</mo><mi>x</mi><mo>&gt;</mo><mn>1</mn></mrow></math>", prefix));
+}
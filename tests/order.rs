@@ -0,0 +1,93 @@
+use rust_cas::{num, parse, parse_as, sym, Expr, Node, Symbol, Token};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher: DefaultHasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Every pair agrees between Eq, Ord and Hash: equal exactly when ordered equal, with equal hashes, and ordered the other way around
+// when swapped
+fn check_consistent<T: Ord + Hash + Debug>(values: &[T]) {
+    for a in values {
+        for b in values {
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{:?} and {:?}", a, b);
+            assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{:?} and {:?}", a, b);
+            if a == b {
+                assert_eq!(hash(a), hash(b), "{:?} and {:?}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn tokens() {
+    let x: Token = Token::VAR(Symbol::new("x"));
+    let y: Token = Token::VAR(Symbol::new("y"));
+    let tokens: Vec<Token> = vec![Token::NUM(2.into()), Token::NUM(2.into()), Token::NUM((-1).into()), x, y, Token::ADD, Token::MUL, Token::LT];
+    check_consistent(&tokens);
+    assert_eq!(tokens[0], tokens[1]);
+    assert_ne!(tokens[0], tokens[2]);
+    assert_ne!(tokens[3], tokens[4]);
+    // NUM values come before VARs, smallest first
+    assert!(tokens[2] < tokens[0]);
+    assert!(tokens[0] < tokens[3]);
+    assert!(tokens[3] < tokens[4]);
+
+    // -0.0 is 0.0 and NaN is itself, so float tokens are still Eq
+    let zero: Token<f64> = Token::NUM(0.0);
+    assert_eq!(zero, Token::NUM(-0.0));
+    assert_eq!(hash(&zero), hash(&Token::<f64>::NUM(-0.0)));
+    assert_eq!(Token::<f64>::NUM(f64::NAN), Token::NUM(f64::NAN));
+    check_consistent(&[zero, Token::NUM(-0.0), Token::NUM(f64::NAN), Token::NUM(1.0), Token::NUM(f64::INFINITY)]);
+}
+
+#[test]
+fn nodes() {
+    let nodes: Vec<Node> = ["x * y", "y * x", "x + y", "x / y", "y / x", "x ^ 2", "2", "x", "( x + 1 ) * 3"].iter().map(|a| *parse(a).unwrap()).collect();
+    check_consistent(&nodes);
+    assert_eq!(nodes[0], nodes[1]);
+    assert_eq!(hash(&nodes[0]), hash(&nodes[1]));
+    assert_ne!(nodes[3], nodes[4]);
+
+    // Equal trees are kept once
+    let set: HashSet<Node> = nodes.iter().cloned().collect();
+    assert_eq!(set.len(), nodes.len() - 1);
+    assert!(set.contains(&*parse("y * x").unwrap()));
+    assert!(!set.contains(&*parse("x - y").unwrap()));
+
+    // A NUM comes before a VAR, and a VAR before an operation
+    let mut sorted: Vec<Node> = vec![*parse("x * y").unwrap(), Node::var("x"), Node::num(3)];
+    sorted.sort();
+    assert_eq!(sorted, vec![Node::num(3), Node::var("x"), *parse("x * y").unwrap()]);
+
+    let floats: Vec<Node<f64>> = ["0.5 * x", "x * 0.5", "x"].iter().map(|a| *parse_as::<f64>(a).unwrap()).collect();
+    check_consistent(&floats);
+    assert_eq!(hash(&floats[0]), hash(&floats[1]));
+}
+
+#[test]
+fn exprs() {
+    let x: Expr = sym("x");
+    let y: Expr = sym("y");
+    let exprs: Vec<Expr> = vec![&x * &y, &x * &y, &y * &x, &x + &y, x.pow(2), num(2), x.clone(), Expr::Func("sin".to_string(), vec![x.clone()])];
+    check_consistent(&exprs);
+    assert_eq!(exprs[0], exprs[1]);
+    // An Expr isn't sorted until it is simplified, so the order of its operands counts
+    assert_ne!(exprs[0], exprs[2]);
+    assert_eq!(exprs[0].simplify(), exprs[2].simplify());
+
+    let set: HashSet<Expr> = exprs.iter().cloned().collect();
+    assert_eq!(set.len(), exprs.len() - 1);
+    assert!(set.contains(&(&x * &y)));
+
+    // NUM values, then symbols, powers, products, sums and functions
+    let mut sorted: Vec<Expr> = vec![Expr::Func("f".to_string(), vec![]), &x + &y, &x * &y, x.pow(2), y.clone(), num(5)];
+    sorted.sort();
+    assert_eq!(sorted, vec![num(5), y.clone(), x.pow(2), &x * &y, &x + &y, Expr::Func("f".to_string(), vec![])]);
+}
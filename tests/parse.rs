@@ -0,0 +1,128 @@
+use rust_cas::{parse, parse_inequality, parse_sexpr, simplify, Node, ParseError, Rational, Token};
+
+#[test]
+fn malformed_expressions() {
+    assert_eq!(parse(")"), Err(ParseError::UnbalancedGroups));
+    assert_eq!(parse("( x + 1"), Err(ParseError::UnbalancedGroups));
+    assert_eq!(parse("x +"), Err(ParseError::ExpectedValue));
+    assert_eq!(parse(""), Err(ParseError::ExpectedValue));
+    assert_eq!(parse("x y"), Err(ParseError::ExpectedOperator));
+    assert_eq!(parse("x < 1"), Err(ParseError::UnexpectedComparison));
+}
+
+#[test]
+fn malformed_inequalities() {
+    assert_eq!(parse_inequality("( 0 < x ) <= 1").err(), Some(ParseError::ComparisonInGroup));
+    assert_eq!(parse_inequality("0 < x > 5").err(), Some(ParseError::MixedComparisons));
+    assert!(parse_inequality("0 < x <= 5").is_ok());
+}
+
+#[test]
+fn malformed_sexprs() {
+    assert_eq!(parse_sexpr("(- x 1)"), Err(ParseError::UnknownOperator(String::from("-"))));
+    assert_eq!(parse_sexpr("(+ x 1 2)"), Err(ParseError::ExpectedClose));
+    assert_eq!(parse_sexpr(")"), Err(ParseError::UnexpectedClose));
+    assert_eq!(parse_sexpr("|x"), Err(ParseError::UnclosedBar));
+    assert_eq!(parse_sexpr("x y"), Err(ParseError::TrailingInput));
+}
+
+#[test]
+fn groups_after_add() {
+    // The group after an ADD is kept, so the MUL after it applies to the whole group
+    assert_eq!(simplify(&parse("1 + ( 2 + 3 ) * 4").unwrap()).to_string(), "21");
+    assert_eq!(parse("1 + ( 2 + x ) * 4").unwrap().to_string(), "1 + 4 * (2 + x)");
+    assert_eq!(simplify(&parse("1 + ( 2 + x )").unwrap()), simplify(&parse("1 + 2 + x").unwrap()));
+}
+
+#[test]
+fn long_expressions() {
+    // A generated expression with tens of thousands of tokens reads into a tree as deep as it is long
+    let terms: Vec<String> = (0..20_000).map(|i| format!("x{:05}", i)).collect();
+    let sum: String = terms.join(" + ");
+    assert_eq!(parse(&sum).unwrap().to_string(), sum);
+    assert_eq!(simplify(&parse(&sum).unwrap()).to_string(), sum);
+    assert_eq!(parse(&format!("{} +", sum)), Err(ParseError::ExpectedValue));
+    assert_eq!(parse(&format!("{} x", sum)), Err(ParseError::ExpectedOperator));
+
+    let nested: String = format!("{}x{}", "( 2 * ".repeat(10_000), " )".repeat(10_000));
+    let power: String = simplify(&parse("2 ^ 10000").unwrap()).to_string();
+    assert_eq!(simplify(&parse(&nested).unwrap()).to_string(), format!("{} * x", power));
+}
+
+#[test]
+fn canonical_chains() {
+    // A sum or product is read into the same tree whatever order its operands are written in, grouped or not
+    assert_eq!(parse("x * y * z"), parse("z * y * x"));
+    assert_eq!(parse("x + y + z"), parse("z + x + y"));
+    assert_eq!(parse("x * ( y * z )"), parse("( z * x ) * y"));
+    assert_eq!(parse("z * y * x").unwrap().to_string(), "x * y * z");
+    assert_eq!(parse("z + x + y").unwrap().to_string(), "x + y + z");
+    assert_eq!(parse("y * 2 + x").unwrap().to_string(), "x + 2 * y");
+
+    // DIV and EXP aren't reordered, and a DIV ends a product
+    assert_ne!(parse("x / y"), parse("y / x"));
+    assert_ne!(parse("x ^ y"), parse("y ^ x"));
+    assert_eq!(parse("z * x / y").unwrap().to_string(), "x * z / y");
+    assert_eq!(parse("y / x * z").unwrap().to_string(), "z * y / x");
+
+    // The simplified output is canonical as well
+    assert_eq!(simplify(&parse("z * y * x").unwrap()), simplify(&parse("x * y * z").unwrap()));
+    assert_eq!(simplify(&parse("z + x + y").unwrap()), simplify(&parse("y + z + x").unwrap()));
+}
+
+#[test]
+fn inequalities() {
+    let inequality = parse_inequality("0 < x <= 10").unwrap();
+    assert_eq!(inequality.terms(), &[Node::num(0), Node::var("x"), Node::num(10)]);
+    assert_eq!(inequality.comparisons(), &[Token::LT, Token::LE]);
+    let relations: Vec<(Node, Token, Node)> = inequality.relations();
+    assert_eq!(relations, vec![(Node::num(0), Token::LT, Node::var("x")), (Node::var("x"), Token::LE, Node::num(10))]);
+    assert_eq!(inequality.to_string(), "0 < x <= 10");
+
+    // Each term is a whole expression, read the same as on its own
+    let inequality = parse_inequality("y + 1 >= 2 * x > x").unwrap();
+    assert_eq!(inequality.terms(), &[*parse("y + 1").unwrap(), *parse("2 * x").unwrap(), Node::var("x")]);
+    assert_eq!(inequality.comparisons(), &[Token::GE, Token::GT]);
+    assert_eq!(inequality.relations().len(), 2);
+
+    // Simplifying simplifies every term, and leaves the comparisons as they are
+    let simplified = parse_inequality("0 < x + x + 1 <= 5 * 2").unwrap().simplify();
+    assert_eq!(simplified.terms(), &[Node::num(0), *simplify(&parse("1 + 2 * x").unwrap()), Node::num(10)]);
+    assert_eq!(simplified.comparisons(), &[Token::LT, Token::LE]);
+    assert_eq!(simplified.to_string(), "0 < 1 + 2 * x <= 10");
+}
+
+#[test]
+fn sexpr_round_trips() {
+    let round_trip = |node: Node| {
+        let sexpr: String = node.to_sexpr();
+        assert_eq!(parse_sexpr(&sexpr).as_deref(), Ok(&node), "{}", sexpr);
+    };
+
+    // Names that would read as something else are written between bars, with a backslash before a bar or backslash
+    for name in ["my var", "|", "a|b", "a\\b", "\\|", "||", "(", "x)", "", " ", "+", "^", "tab\there"] {
+        round_trip(Node::var(name));
+    }
+    assert_eq!(Node::<Rational>::var("my var").to_sexpr(), "|my var|");
+    assert_eq!(Node::<Rational>::var("a|b\\c").to_sexpr(), "|a\\|b\\\\c|");
+    assert_eq!(Node::<Rational>::var("x-1").to_sexpr(), "x-1");
+
+    // Negative and fractional numbers, and names that look like them
+    for num in [Rational::from(-3), Rational::new(1, 3), Rational::new(-5, 2), Rational::from(0), Rational::new(1, 4)] {
+        round_trip(Node::num(num));
+    }
+    for name in ["3", "-2", "1/3", "-5/2", "0.5", "1e5", "+7", "007"] {
+        round_trip(Node::var(name));
+        assert!(Node::<Rational>::var(name).to_sexpr().starts_with('|'), "{}", name);
+    }
+    assert_eq!(Node::<Rational>::num(Rational::new(-5, 2)).to_sexpr(), "-5/2");
+
+    // And all of them inside of a tree
+    let tree: Node = Node::operation(
+        Token::ADD,
+        Node::operation(Token::MUL, Node::num(Rational::new(-1, 3)), Node::var("1/3")),
+        Node::operation(Token::EXP, Node::var("my |var|"), Node::operation(Token::DIV, Node::num(-2), Node::var("-2"))),
+    );
+    round_trip(tree);
+    round_trip(*parse("( x + 1 ) ^ ( 0 - 1 / 2 ) * y").unwrap());
+}
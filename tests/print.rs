@@ -0,0 +1,67 @@
+use rust_cas::{dot_inequality_comparison, parse, parse_as, parse_inequality, simplify, BigFloat};
+
+#[test]
+fn negative_fraction() {
+    let node = simplify(&parse("( 0 - 1 ) / 2 * x").unwrap());
+    assert_eq!(node.to_string(), "-1 / 2 * x");
+    assert_eq!(node.to_pretty(false), "   1\n- --- * x\n   2");
+    assert_eq!(node.to_pretty(true), "   1\n- ───⋅x\n   2");
+    assert_eq!(simplify(&parse("0 - x").unwrap()).to_pretty(false), "-x");
+}
+
+// The lines of the pretty printed expression in ASCII and in Unicode
+fn pretty(input: &str) -> (String, String) {
+    let node = parse(input).unwrap();
+    (node.to_pretty(false), node.to_pretty(true))
+}
+
+#[test]
+fn pretty_exponents() {
+    // Raised above the base, or written as a superscript in Unicode when the exponent is a NUM
+    assert_eq!(pretty("x ^ 2"), (" 2\nx".to_string(), "x²".to_string()));
+    assert_eq!(pretty("2 * x ^ 2"), ("     2\n2 * x".to_string(), "2⋅x²".to_string()));
+    assert_eq!(pretty("( x + 1 ) ^ 2"), ("       2\n(1 + x)".to_string(), "(1 + x)²".to_string()));
+    assert_eq!(pretty("x ^ ( y + 1 )"), (" 1 + y\nx".to_string(), " 1 + y\nx".to_string()));
+    assert_eq!(pretty("x ^ ( 1 / 3 )"), ("  1\n ---\n  3\nx".to_string(), "  1\n ───\n  3\nx".to_string()));
+    // A base that is a power is wrapped in parentheses as tall as it is
+    assert_eq!(pretty("x ^ y ^ z"), ("    z\n/ y\\\n\\x /".to_string(), "    z\n⎛ y⎞\n⎝x ⎠".to_string()));
+}
+
+#[test]
+fn pretty_radicals() {
+    // A power of 1/2 is a square root, with the overline as wide as what is under it
+    assert_eq!(pretty("x ^ ( 1 / 2 )"), ("  __\n\\/ x".to_string(), "  __\n╲╱ x".to_string()));
+    assert_eq!(pretty("( x + 1 ) ^ ( 1 / 2 )"), ("  ______\n\\/ 1 + x".to_string(), "  ______\n╲╱ 1 + x".to_string()));
+}
+
+#[test]
+fn pretty_fractions() {
+    // A fraction bar as wide as the wider side, with one more on either side of a fraction holding another
+    assert_eq!(pretty("x / y"), (" x\n---\n y".to_string(), " x\n───\n y".to_string()));
+    assert_eq!(pretty("( x / y ) / z"), ("  x\n ---\n  y\n-----\n  z".to_string(), "  x\n ───\n  y\n─────\n  z".to_string()));
+    let (ascii, unicode): (String, String) = pretty("1 / ( 1 + 1 / x )");
+    assert_eq!(ascii, "    1\n---------\n      1\n 1 + ---\n      x");
+    assert_eq!(unicode, "    1\n─────────\n      1\n 1 + ───\n      x");
+
+    let inequality = parse_inequality("0 < x / 2 <= 10").unwrap();
+    assert_eq!(inequality.to_pretty(false), "     x\n0 < --- <= 10\n     2");
+    assert_eq!(inequality.to_pretty(true), "     x\n0 < ─── ≤ 10\n     2");
+}
+
+#[test]
+fn bigfloat_digits() {
+    let large = simplify(&parse_as::<BigFloat>("2 ^ 1000").unwrap()).to_string();
+    assert_eq!(large, "1.07150860718626732094842504906000181056140481170553360744375038837035105112e301");
+    let plain = simplify(&parse_as::<BigFloat>("2 ^ 200 + 0.5").unwrap()).to_string();
+    assert_eq!(plain, "1606938044258990275541962092341162602522202993782792835301376.5");
+    assert_eq!(simplify(&parse_as::<BigFloat>("0.5 ^ 10").unwrap()).to_string(), "0.0009765625");
+}
+
+#[test]
+fn inequality_dot() {
+    let inequality = parse_inequality("0 < x + x <= 10").unwrap();
+    let dot: String = dot_inequality_comparison(&inequality, &inequality.simplify());
+    assert!(dot.contains("c0_n0 -> c0_n1 [label=\"<\", style=dashed];"));
+    assert!(dot.contains("c1_n1 -> c1_n4 [label=\"<=\", style=dashed];"));
+    assert!(dot.contains("c1_n1 [label=\"*\", shape=ellipse];"));
+}
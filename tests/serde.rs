@@ -0,0 +1,88 @@
+// Round trips of expression trees through the JSON schema in the README.
+use rust_cas::{parse, parse_as, simplify, Complex, Node, Rational};
+
+fn round_trip(node: &Node) -> Node {
+    let json: String = serde_json::to_string(node).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn schema() {
+    let node = parse("2 + x").unwrap();
+    assert_eq!(
+        serde_json::to_string(&node).unwrap(),
+        r#"{"data_type":"ADD","left":{"data_type":{"NUM":2}},"right":{"data_type":{"VAR":"x"}}}"#
+    );
+}
+
+#[test]
+fn parse_round_trip() {
+    for input in ["2 + x", "x ^ 2 / ( y - 3 )", "( x + 1 ) ( x - 1 )", "-4 * y ^ -0.5 + x / x", "a * b * c * 1.25"] {
+        let node = parse(input).unwrap();
+        let back = round_trip(&node);
+        assert_eq!(back, *node);
+        assert_eq!(format!("{:?}", back), format!("{:?}", node));
+    }
+}
+
+#[test]
+fn simplified_round_trip() {
+    for input in ["x + 2 + x", "x * y * x / y", "3 - x + 4"] {
+        let node = simplify(&parse(input).unwrap());
+        let back = round_trip(&node);
+        assert_eq!(back, *node);
+        assert_eq!(format!("{:?}", back), format!("{:?}", node));
+    }
+}
+
+#[test]
+fn fractions() {
+    let node: Node = Node::num(Rational::new(-1, 3));
+    assert_eq!(serde_json::to_string(&node).unwrap(), r#"{"data_type":{"NUM":"-1/3"}}"#);
+    let decimal: Node = serde_json::from_str(r#"{"data_type":{"NUM":0.25}}"#).unwrap();
+    assert_eq!(decimal, Node::num(Rational::new(1, 4)));
+    let large = simplify(&parse("2 ^ 100").unwrap());
+    assert_eq!(serde_json::to_string(&large).unwrap(), r#"{"data_type":{"NUM":"1267650600228229401496703205376"}}"#);
+    assert_eq!(round_trip(&large), *large);
+}
+
+#[test]
+fn leaves_without_branches() {
+    let node: Node = serde_json::from_str(r#"{"data_type":{"VAR":"x"},"left":null}"#).unwrap();
+    assert_eq!(node, Node::var("x"));
+}
+
+#[test]
+fn floats() {
+    let node = parse_as::<f64>("0.5 * x").unwrap();
+    let json: String = serde_json::to_string(&node).unwrap();
+    assert_eq!(json, r#"{"data_type":"MUL","left":{"data_type":{"NUM":0.5}},"right":{"data_type":{"VAR":"x"}}}"#);
+    let read: Node<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(read, *node);
+}
+
+#[test]
+fn complex() {
+    let node = simplify(&parse_as::<Complex<Rational>>("3 + 2 * i + x").unwrap());
+    let json: String = serde_json::to_string(&node).unwrap();
+    assert_eq!(json, r#"{"data_type":"ADD","left":{"data_type":{"NUM":"3+2i"}},"right":{"data_type":{"VAR":"x"}}}"#);
+    let read: Node<Complex<Rational>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(read, *node);
+}
+
+#[test]
+fn malformed_nodes() {
+    let invalid = [
+        r#"{"data_type":"ADD"}"#,
+        r#"{"data_type":"MUL","left":{"data_type":{"NUM":2}}}"#,
+        r#"{"data_type":{"NUM":2},"left":{"data_type":{"VAR":"x"}}}"#,
+        r#"{"data_type":"LGROUP"}"#,
+        r#"{"data_type":"ADD","left":{"data_type":"LT"},"right":{"data_type":{"VAR":"x"}}}"#,
+        r#"{"data_type":"EXP","left":{"data_type":{"VAR":"x"}},"right":{"data_type":"DIV"}}"#,
+    ];
+    for json in invalid {
+        assert!(serde_json::from_str::<Node>(json).is_err(), "{} was read", json);
+    }
+    let error: String = serde_json::from_str::<Node>(r#"{"data_type":"ADD"}"#).unwrap_err().to_string();
+    assert_eq!(error, "The operator ADD is missing a value!");
+}
@@ -0,0 +1,160 @@
+use rust_cas::{parse, parse_as, simplify, BigFloat, Complex, Expr, Node, Numeric, Rational, Token};
+
+fn simplified(input: &str) -> String {
+    simplify(&parse(input).unwrap()).to_string()
+}
+
+#[test]
+fn division_by_zero() {
+    assert_eq!(simplified("x / 0"), "x / 0");
+    assert_eq!(simplified("1 / 0"), "1 / 0");
+    assert_eq!(simplified("0 ^ -1"), "1 / 0");
+    assert_eq!(simplified("3 * ( x / 0 )"), "3 * x / 0");
+    assert_eq!(simplified("x / ( 0 * y )"), "x / 0");
+    assert_eq!(simplified("0 / 0"), "0 / 0");
+    assert_eq!(simplified("0 / x"), "0");
+    let complex = simplify(&parse_as::<Complex<Rational>>("( 2 * i ) / 0").unwrap());
+    assert_eq!(complex.to_string(), "2 * i / 0");
+}
+
+#[test]
+fn float_division_by_zero() {
+    for input in ["1 / 0", "x / 0", "0 ^ -1", "0 / 0"] {
+        let expected: String = simplified(input);
+        assert_eq!(simplify(&parse_as::<f32>(input).unwrap()).to_string(), expected);
+        assert_eq!(simplify(&parse_as::<f64>(input).unwrap()).to_string(), expected);
+        assert_eq!(simplify(&parse_as::<BigFloat>(input).unwrap()).to_string(), expected);
+        assert_eq!(simplify(&parse_as::<Complex<f64>>(input).unwrap()).to_string(), expected);
+    }
+}
+
+#[test]
+fn complex_values() {
+    use std::collections::HashMap;
+    let mut values: HashMap<String, Complex<Rational>> = HashMap::new();
+    values.insert("x".to_string(), Complex::new(Rational::from(2), Rational::from(0)));
+    let complex = simplify(&parse_as::<Complex<Rational>>("( 0 - 4 ) ^ 0.5 * x").unwrap());
+    assert_eq!(complex.to_string(), "2 * i * x");
+    assert_eq!(complex.evaluate(&values), Some(Complex::new(Rational::from(0), Rational::from(4))));
+    let real = simplify(&parse_as::<Complex<Rational>>("i ^ 2 * x").unwrap());
+    assert_eq!(real.evaluate(&values), Some(Complex::new(Rational::from(-2), Rational::from(0))));
+
+    let big = simplify(&parse_as::<Complex<BigFloat>>("( 3 + 4 * i ) ^ 0.5 + ( 0 - 4 ) ^ 0.5").unwrap());
+    assert_eq!(big.to_string(), "2 + 3 * i");
+}
+
+#[test]
+fn evaluation() {
+    use std::collections::HashMap;
+    // An expression is evaluated in the numbers it was read with, so a Rational stays exact and a float can hold a root
+    let mut values: HashMap<String, Rational> = HashMap::new();
+    values.insert("x".to_string(), Rational::new(1, 3));
+    assert_eq!(parse("x * 3 + x / 2").unwrap().evaluate(&values), Some(Rational::new(7, 6)));
+    assert_eq!(parse("2 ^ x").unwrap().evaluate(&values), None);
+    assert_eq!(parse("x / ( x - x )").unwrap().evaluate(&values), None);
+    assert_eq!(parse("x + y").unwrap().evaluate(&values), None);
+
+    let mut values: HashMap<String, f64> = HashMap::new();
+    values.insert("x".to_string(), 2.0);
+    assert_eq!(parse_as::<f64>("x ^ 0.5 * x ^ 0.5").unwrap().evaluate(&values), Some(2.0000000000000004));
+    assert_eq!(rust_cas::evaluate(&parse_as::<f64>("x / 4").unwrap(), &values), Some(0.5));
+}
+
+#[test]
+fn roots() {
+    assert_eq!(simplified("12 ^ 0.5"), "2 * 3 ^ (1 / 2)");
+    assert_eq!(simplified("( 1 / 2 ) ^ 0.5"), "1 / 2 * 2 ^ (1 / 2)");
+    assert_eq!(simplified("2 ^ ( 0 - 1 / 2 )"), "1 / 2 * 2 ^ (1 / 2)");
+    assert_eq!(simplified("18 ^ ( 1 / 3 ) * 12 ^ ( 1 / 3 )"), "6");
+    assert_eq!(simplified("2 ^ 0.5 * 3 ^ 0.5 - 6 ^ 0.5"), "0");
+    // 4099 is a prime above the factors that are tried one by one, so its square only comes out when what is left is a perfect square
+    assert_eq!(simplified("( 4099 * 4099 * 3 ) ^ 0.5"), "4099 * 3 ^ (1 / 2)");
+    assert_eq!(simplified("( 4099 * 4099 ) ^ 0.5"), "4099");
+    assert_eq!(simplified("( 4099 * 4099 * 4111 ) ^ 0.5"), "69072203911 ^ (1 / 2)");
+}
+
+#[test]
+fn roots_of_negative_numbers() {
+    // The square roots of negative numbers don't multiply like those of positive numbers, i * 2^(1/2) * i * 3^(1/2) is -6^(1/2)
+    assert_eq!(simplified("( 0 - 2 ) ^ 0.5 * ( 0 - 3 ) ^ 0.5"), "(-3) ^ (1 / 2) * (-2) ^ (1 / 2)");
+    let complex = simplify(&parse_as::<Complex<Rational>>("( 0 - 2 ) ^ 0.5 * ( 0 - 3 ) ^ 0.5").unwrap());
+    assert_eq!(complex.to_string(), "-6 ^ (1 / 2)");
+}
+
+fn simplified_as<N: Numeric>(input: &str) -> String {
+    simplify(&parse_as::<N>(input).unwrap()).to_string()
+}
+
+#[test]
+fn principal_roots() {
+    // A root of a negative number is the principal one whatever the numbers are, so (-8)^(1/3) is 1 + 3^(1/2) * i and never -2. The
+    // types that can't hold it leave the power as it is.
+    let cube_root: &str = "( 0 - 8 ) ^ ( 1 / 3 )";
+    assert_eq!(simplified_as::<Rational>(cube_root), "(-8) ^ (1 / 3)");
+    assert_eq!(simplified_as::<Complex<Rational>>(cube_root), "(-8) ^ (1 / 3)");
+    assert_eq!(simplified_as::<f32>(cube_root), "(-8) ^ 0.33333334");
+    assert_eq!(simplified_as::<f64>(cube_root), "(-8) ^ 0.3333333333333333");
+    assert!(simplified_as::<BigFloat>(cube_root).starts_with("(-8) ^ 0.333"));
+    assert!(simplified_as::<Complex<BigFloat>>(cube_root).starts_with("(-8) ^ 0.333"));
+    assert_eq!(simplified_as::<Complex<f64>>(cube_root), "1 + 1.732050807568877 * i");
+    assert_eq!(simplified_as::<Rational>("( 0 - 8 ) ^ ( 2 / 3 )"), "(-8) ^ (2 / 3)");
+
+    // Square roots are principal too, and integer powers are the same for every type
+    assert_eq!(simplified_as::<Complex<Rational>>("( 0 - 4 ) ^ 0.5"), "2 * i");
+    assert_eq!(simplified_as::<Complex<f64>>("( 0 - 4 ) ^ 0.5"), "2 * i");
+    assert_eq!(simplified_as::<Complex<BigFloat>>("( 0 - 4 ) ^ 0.5"), "2 * i");
+    assert_eq!(simplified_as::<Rational>("( 0 - 4 ) ^ 0.5"), "(-4) ^ (1 / 2)");
+    assert_eq!(simplified_as::<Rational>("( 0 - 8 ) ^ 3"), "-512");
+    assert_eq!(simplified_as::<f64>("( 0 - 8 ) ^ 3"), "-512");
+    assert_eq!(simplified_as::<Complex<f64>>("( 0 - 8 ) ^ 3"), "-512");
+    assert_eq!(Rational::from(-8).pow(&Rational::new(1, 3)), None);
+    assert_eq!(Rational::from(-8).pow(&Rational::from(-1)), Some(Rational::new(-1, 8)));
+}
+
+#[test]
+fn distinct_variables() {
+    // The rules for like terms and like factors only hold for the same VAR on both sides, x * y isn't x ^ 2
+    assert_eq!(simplified("x * y"), "x * y");
+    assert_eq!(simplified("x + y"), "x + y");
+    assert_eq!(simplified("x / y"), "x / y");
+    assert_eq!(simplified("x - y"), "x - y");
+    assert_eq!(simplified("x * ( 2 * y ^ 3 )"), "2 * x * y ^ 3");
+    assert_eq!(simplified("x * y ^ 2"), "x * y ^ 2");
+    assert_eq!(simplified("y ^ 2 / x"), "y ^ 2 / x");
+    assert_eq!(simplified("( 2 * y ) / x"), "2 * y / x");
+    assert_eq!(simplified("x ^ 2 / y ^ 2"), "x ^ 2 / y ^ 2");
+    // Only a sum of x and -1 * x cancels out, not a product
+    assert_eq!(simplified("x * ( -1 * x )"), "-x ^ 2");
+}
+
+#[test]
+fn long_sums() {
+    // A generated sum is a chain as deep as it is long, which every step has to get through without running out of stack
+    let names: Vec<String> = (0..10_000).map(|i| format!("x{:05}", i)).collect();
+    let mut node: Node = Node::var(&names[0]);
+    for a in &names[1..] {
+        node = Node::operation(Token::ADD, node, Node::var(a));
+    }
+    assert_eq!(node.clone(), node);
+    match Expr::from(&node) {
+        Expr::Add(terms) => assert_eq!(terms.len(), 10_000),
+        a => panic!("Expected a sum, found {:?}", a),
+    }
+    assert_eq!(simplify(&node).to_string(), names.join(" + "));
+
+    // The same sum chained down the right
+    let mut node: Node = Node::var(&names[9_999]);
+    for a in names[..9_999].iter().rev() {
+        node = Node::operation(Token::ADD, Node::var(a), node);
+    }
+    assert_eq!(node.to_string(), names.join(" + "));
+    assert_eq!(simplify(&node).to_string(), names.join(" + "));
+
+    let mut node: Node = Node::var("x");
+    for i in 1..10_000 {
+        node = Node::operation(Token::ADD, node, Node::operation(Token::MUL, Node::num(i), Node::var("x")));
+    }
+    assert_eq!(simplify(&node).to_string(), "49995001 * x");
+    let values: std::collections::HashMap<String, Rational> = vec![("x".to_string(), Rational::from(2))].into_iter().collect();
+    assert_eq!(node.evaluate(&values), Some(Rational::from(99990002)));
+}
@@ -0,0 +1,46 @@
+use rust_cas::{parse, parse_as, sym, Expr, ExprId, ExprStore, Stored};
+
+#[test]
+fn equal_subexpressions_share_an_id() {
+    let mut store: ExprStore = ExprStore::new();
+    let x = sym("x");
+    let y = sym("y");
+    let sum: ExprId = store.insert(&(&x * &y + &x * &y));
+    // x, y, x * y and the sum
+    assert_eq!(store.len(), 4);
+    match store.get(sum) {
+        Stored::Add(terms) => assert_eq!(terms[0], terms[1]),
+        a => panic!("Expected a sum, found {:?}", a),
+    }
+    assert_eq!(store.insert(&(&x * &y)), store.insert(&(&x * &y)));
+    assert_ne!(store.insert(&(&x * &y)), store.insert(&(&y * &x)));
+}
+
+#[test]
+fn round_trip() {
+    let mut store: ExprStore = ExprStore::new();
+    let expr: Expr = sym("x").pow(2) + 3 * sym("x") - sym("y") / 2;
+    let id: ExprId = store.insert(&expr);
+    assert_eq!(store.to_expr(id), expr);
+
+    let node = parse("x / y + 2").unwrap();
+    let id: ExprId = store.insert_node(&node);
+    assert_eq!(store.to_expr(id), Expr::from(&*node));
+}
+
+#[test]
+fn floats_share_an_id() {
+    let mut store: ExprStore<f64> = ExprStore::new();
+    let zero: ExprId = store.insert(&Expr::Num(0.0));
+    assert_eq!(store.insert(&Expr::Num(-0.0)), zero);
+    let node = parse_as::<f64>("0.5 * x + 0.5 * x").unwrap();
+    let id: ExprId = store.insert_node(&node);
+    assert_eq!(store.to_expr(id).simplify(), Expr::from(&*parse_as::<f64>("1 * x").unwrap()).simplify());
+}
+
+#[test]
+fn shared_subexpressions_simplify_the_same() {
+    let inner: Expr = (sym("x") + sym("x")).pow(2);
+    let expr: Expr = &inner * &inner + &inner;
+    assert_eq!(expr.simplify(), (2 * sym("x")).pow(2) + (2 * sym("x")).pow(4));
+}
@@ -0,0 +1,50 @@
+use rust_cas::{parse, Expr, Symbol};
+use std::collections::{BTreeSet, HashSet};
+use std::thread;
+
+#[test]
+fn interning() {
+    let a: Symbol = Symbol::new("interned");
+    assert_eq!(a, Symbol::new("interned"));
+    assert_eq!(a, Symbol::from("interned"));
+    assert_ne!(a, Symbol::new("Interned"));
+    assert_eq!(a.as_str(), "interned");
+    assert_eq!(Symbol::new("").as_str(), "");
+    assert_ne!(Symbol::new(""), Symbol::new(" "));
+    // The name is stored once, however many times it is read
+    let name: String = ["inter", "ned"].concat();
+    assert!(std::ptr::eq(a.as_str(), Symbol::new(&name).as_str()));
+
+    match *parse("interned").unwrap().data_type() {
+        rust_cas::Token::VAR(b) => assert_eq!(b, a),
+        ref b => panic!("Expected a VAR, found {:?}", b),
+    }
+    let set: HashSet<Symbol> = ["interned", "other", "interned"].iter().map(|a| Symbol::new(a)).collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn order() {
+    // Ordered by name, not by when each name was first seen
+    let late: Symbol = Symbol::new("order_z");
+    let early: Symbol = Symbol::new("order_a");
+    assert!(early < late);
+    assert_eq!(late.cmp(&late), std::cmp::Ordering::Equal);
+    let sorted: BTreeSet<Symbol> = ["order_m", "order_z", "order_a", "order_B"].iter().map(|a| Symbol::new(a)).collect();
+    let names: Vec<&str> = sorted.iter().map(|a| a.as_str()).collect();
+    assert_eq!(names, vec!["order_B", "order_a", "order_m", "order_z"]);
+    assert!(Expr::from(early) < Expr::from(late));
+}
+
+#[test]
+fn threads() {
+    // Every thread gets the same Symbol for a name, even when they all add it at once
+    let names = || -> Vec<Symbol> { (0..100).map(|i| Symbol::new(&format!("thread_{}", i))).collect() };
+    let handles: Vec<thread::JoinHandle<Vec<Symbol>>> = (0..8).map(|_| thread::spawn(names)).collect();
+    let symbols: Vec<Vec<Symbol>> = handles.into_iter().map(|a| a.join().unwrap()).collect();
+    for a in &symbols[1..] {
+        assert_eq!(a, &symbols[0]);
+    }
+    assert_eq!(symbols[0].iter().collect::<HashSet<&Symbol>>().len(), 100);
+    assert_eq!(symbols[0][42].as_str(), "thread_42");
+}
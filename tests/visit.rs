@@ -0,0 +1,124 @@
+use rust_cas::{fold_operands, num, sym, walk, walk_mut, Expr, Fold, MutVisitor, Rational, Symbol, Visitor};
+
+fn sin(argument: Expr) -> Expr {
+    Expr::Func("sin".to_string(), vec![argument])
+}
+
+// Every subexpression in the order it is visited, as printed by Debug
+struct Order {
+    seen: Vec<String>,
+}
+
+impl Visitor for Order {
+    fn visit(&mut self, expr: &Expr) {
+        self.seen.push(format!("{:?}", expr));
+        walk(self, expr);
+    }
+}
+
+// The sum of the NUM values, without overriding visit
+struct Sum {
+    total: Rational,
+}
+
+impl Visitor for Sum {
+    fn visit_num(&mut self, num: &Rational) {
+        self.total += num.clone();
+    }
+}
+
+#[test]
+fn visitor() {
+    let expr: Expr = sym("x").pow(2) + 3;
+    let mut order: Order = Order { seen: Vec::new() };
+    order.visit(&expr);
+    let expected: Vec<String> = [&expr, &sym("x").pow(2), &sym("x"), &num(2), &num(3)].iter().map(|a| format!("{:?}", a)).collect();
+    assert_eq!(order.seen, expected);
+
+    let mut sum: Sum = Sum { total: Rational::from(0) };
+    sum.visit(&(sin(sym("x") * 4) + sym("y").pow(Rational::new(1, 2)) + 3));
+    assert_eq!(sum.total, Rational::new(15, 2));
+}
+
+// Doubles every NUM in place
+struct Double;
+
+impl MutVisitor for Double {
+    fn visit_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Num(a) = expr {
+            *a *= Rational::from(2);
+        }
+        walk_mut(self, expr);
+    }
+}
+
+#[test]
+fn mut_visitor() {
+    let mut expr: Expr = sin(sym("x").pow(3)) * 5 + 1;
+    Double.visit_mut(&mut expr);
+    assert_eq!(expr, sin(sym("x").pow(6)) * 10 + 2);
+
+    // A MutVisitor that overrides nothing leaves the expression as it is
+    struct Nothing;
+    impl MutVisitor for Nothing {}
+    let mut unchanged: Expr = expr.clone();
+    Nothing.visit_mut(&mut unchanged);
+    assert_eq!(unchanged, expr);
+}
+
+// Turns every power into a product of its base, for whole exponents
+struct Expand;
+
+impl Fold for Expand {
+    fn fold(&mut self, expr: Expr) -> Expr {
+        match fold_operands(self, expr) {
+            Expr::Pow(base, exponent) => match *exponent {
+                Expr::Num(a) if a.is_integer() && a > Rational::from(0) => {
+                    let count: i64 = a.to_f64() as i64;
+                    Expr::Mul((0..count).map(|_| (*base).clone()).collect())
+                }
+                a => Expr::Pow(base, Box::new(a)),
+            },
+            a => a,
+        }
+    }
+}
+
+#[test]
+fn fold() {
+    // The operands are folded first, so the inner power is expanded before the outer one is
+    let expr: Expr = sym("x").pow(sym("y").pow(2)) + sin(sym("z").pow(3));
+    let expected: Expr = sym("x").pow(Expr::Mul(vec![sym("y"), sym("y")])) + sin(Expr::Mul(vec![sym("z"), sym("z"), sym("z")]));
+    assert_eq!(Expand.fold(expr), expected);
+    assert_eq!(Expand.fold(sym("x").pow(sym("n"))), sym("x").pow(sym("n")));
+}
+
+#[test]
+fn free_symbols() {
+    let names = |expr: &Expr| -> Vec<&'static str> { expr.free_symbols().into_iter().map(|a| a.as_str()).collect() };
+    assert_eq!(names(&num(3)), Vec::<&str>::new());
+    assert_eq!(names(&(sym("y") * sym("x") + sym("y"))), vec!["x", "y"]);
+    // Symbols in the exponent of a power and the arguments of a function count too
+    assert_eq!(names(&(sym("b").pow(sym("c")) + sin(sym("a")))), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn depth() {
+    assert_eq!(num(1).depth(), 1);
+    assert_eq!(sym("x").depth(), 1);
+    assert_eq!((sym("x") + sym("y") + sym("z")).depth(), 2);
+    assert_eq!((sym("x").pow(sym("y") * 2) + 1).depth(), 4);
+    assert_eq!(sin(sin(sym("x"))).depth(), 3);
+}
+
+#[test]
+fn substitute() {
+    let x: Symbol = Symbol::new("x");
+    assert_eq!((sym("x") + sym("y")).substitute(x, &num(2)), num(2) + sym("y"));
+    // Inside the base and exponent of a power, and the arguments of a function
+    assert_eq!(sym("x").pow(sym("x")).substitute(x, &sym("z")), sym("z").pow(sym("z")));
+    assert_eq!((sin(sym("x") * 2) + 1).substitute(x, &sym("t").pow(2)), sin(sym("t").pow(2) * 2) + 1);
+    // The replacement isn't substituted into again, and other symbols are left alone
+    assert_eq!(sym("x").pow(2).substitute(x, &(sym("x") + 1)), (sym("x") + 1).pow(2));
+    assert_eq!(sin(sym("y")).substitute(x, &num(0)), sin(sym("y")));
+}